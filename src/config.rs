@@ -3,7 +3,7 @@
 //! This module defines compile-time configuration constants for USB HID logging
 //! and other system parameters.
 
-use crate::logging::LogLevel;
+use crate::types::logging::LogLevel;
 use core::result::Result::{self, Ok, Err};
 use core::option::Option::{self, Some, None};
 use core::default::Default;
@@ -108,6 +108,25 @@ pub mod system {
     pub const MAX_USB_CPU_USAGE_PERCENT: u8 = 5;
 }
 
+/// `ResourceUsageFilter` hysteresis thresholds: "enter" trips the filter
+/// into `Blocked`, "exit" is the lower bar it must fall back under to clear
+/// - a gap between the two keeps the filter from flapping right at a
+/// boundary
+pub mod resource_filter {
+    /// Avg60 CPU pressure percent that trips the filter into `Blocked`
+    pub const CPU_ENTER_PERCENT: u8 = 8;
+    /// Avg60 CPU pressure percent required to clear `Blocked`
+    pub const CPU_EXIT_PERCENT: u8 = 5;
+    /// Avg60 memory pressure percent that trips the filter into `Blocked`
+    pub const MEMORY_ENTER_PERCENT: u8 = 15;
+    /// Avg60 memory pressure percent required to clear `Blocked`
+    pub const MEMORY_EXIT_PERCENT: u8 = 10;
+    /// Max observed timing deviation (microseconds) that trips the filter
+    pub const TIMING_ENTER_US: u32 = 15_000;
+    /// Max observed timing deviation (microseconds) required to clear `Blocked`
+    pub const TIMING_EXIT_US: u32 = 10_000;
+}
+
 /// Task Priority Configuration
 pub mod priorities {
     /// pEMF pulse generation task priority (highest)
@@ -138,6 +157,139 @@ pub mod pins {
     pub const BATTERY_ADC_PIN: u8 = 26;
 }
 
+/// Battery Temperature Sensing Calibration
+///
+/// Linear calibration for an MCP9700-style analog sensor (10mV/°C, 500mV at
+/// 0°C) feeding a dedicated battery-temperature ADC channel, alongside the
+/// high/low thresholds `BatteryHealth::evaluate` classifies against.
+pub mod battery_temp {
+    /// Calibration slope converting a raw temperature-channel ADC reading to
+    /// milli-degrees Celsius, in mdeg per ADC LSB (12-bit ADC, 3.3V reference,
+    /// 10mV/°C sensor output: (3300mV / 4095) / 10mV/°C * 1000 mdeg/°C)
+    pub const TEMP_ADC_SLOPE_MDEG_PER_LSB: i32 = 81;
+
+    /// Calibration offset in milli-degrees Celsius at ADC reading 0
+    /// (extrapolated from the sensor's 500mV-at-0°C bias)
+    pub const TEMP_ADC_OFFSET_MDEG: i32 = -50_000;
+
+    /// Above this temperature, `BatteryHealth::evaluate` reports `Overheat`
+    pub const HIGH_TEMP_THRESHOLD_MDEG: i32 = 50_000;
+
+    /// Below this temperature, `BatteryHealth::evaluate` reports `Cold`
+    pub const LOW_TEMP_THRESHOLD_MDEG: i32 = 0;
+}
+
+/// Fuel Gauge Configuration
+pub mod fuel_gauge {
+    /// One open-circuit-voltage breakpoint: a voltage in millivolts and the
+    /// state-of-charge percentage it maps to. `FuelGauge::reset_from_voltage`
+    /// linearly interpolates between the two breakpoints surrounding a
+    /// reading, clamping to the first/last entry outside the table's range.
+    pub struct OcvBreakpoint {
+        pub voltage_mv: u32,
+        pub soc_percent: u8,
+    }
+
+    /// Open-circuit-voltage curve, sorted ascending by `voltage_mv`
+    pub const OCV_TABLE: [OcvBreakpoint; 7] = [
+        OcvBreakpoint { voltage_mv: 3000, soc_percent: 0 },
+        OcvBreakpoint { voltage_mv: 3300, soc_percent: 10 },
+        OcvBreakpoint { voltage_mv: 3600, soc_percent: 25 },
+        OcvBreakpoint { voltage_mv: 3800, soc_percent: 50 },
+        OcvBreakpoint { voltage_mv: 4000, soc_percent: 75 },
+        OcvBreakpoint { voltage_mv: 4150, soc_percent: 90 },
+        OcvBreakpoint { voltage_mv: 4200, soc_percent: 100 },
+    ];
+
+    /// Nominal pack capacity the coulomb counter integrates against
+    pub const BATTERY_CAPACITY_MAH: u32 = 2000;
+
+    /// Current magnitude below which the pack is considered at rest, so
+    /// `FuelGauge::update` re-anchors to the OCV estimate to correct
+    /// coulomb-counting drift
+    pub const REST_CURRENT_THRESHOLD_MA: i32 = 20;
+
+    /// Voltage at/below which a charger-attached reading is treated as the
+    /// "UV charging case": a deeply depleted cell that happens to read a
+    /// plausible voltage once the charger is attached. `FuelGauge` forces
+    /// SoC to 0 and suppresses OCV re-anchoring while in this condition.
+    pub const UV_CHARGING_VOLTAGE_MV: u32 = 3200;
+
+    /// Voltage the UV-charging case must reach before `FuelGauge`
+    /// recalibrates from OCV and clears the low-battery-boot flag
+    pub const UV_CHARGING_RECOVERY_VOLTAGE_MV: u32 = 3400;
+}
+
+/// Voltage Filter Configuration
+///
+/// Parameters for `utils::voltage_filter::VoltageFilter`'s moving average
+/// and spike rejection.
+pub mod voltage_filter {
+    /// Number of most-recent raw ADC samples averaged by
+    /// `VoltageFilter::average`
+    pub const WINDOW_SAMPLES: usize = 8;
+
+    /// A new sample deviating from the current moving average by more than
+    /// this many ADC LSBs (`MAX_ADC_JUMP`) is treated as a glitch: the
+    /// accepted change is clamped to this delta rather than following the
+    /// sample outright
+    pub const SPIKE_REJECT_DELTA_ADC: u16 = 200;
+
+    /// Consecutive samples that must agree on a jump bigger than
+    /// `SPIKE_REJECT_DELTA_ADC` before `VoltageFilter` stops clamping and
+    /// commits it outright - a real step in battery voltage (e.g. a charger
+    /// being plugged in) persists for many samples, while a glitched
+    /// conversion is gone by the next one
+    pub const JUMP_CONFIRM_SAMPLES: u8 = 3;
+}
+
+/// Battery State Tracker Configuration
+///
+/// Hysteresis and debounce parameters for
+/// `types::battery::BatteryStateTracker`, which wraps
+/// `BatteryState::from_adc_reading`'s pure threshold lookup to stop a
+/// reading that hovers near a boundary from flapping between states every
+/// sample.
+pub mod battery_state_tracker {
+    /// ADC margin added to a threshold on the side the tracker's current
+    /// state already occupies, so small jitter near a boundary doesn't
+    /// register as a candidate for the neighboring state
+    pub const HYSTERESIS_ADC: u16 = 15;
+
+    /// Consecutive samples a candidate state must be observed before
+    /// `BatteryStateTracker::update` commits it
+    pub const DEBOUNCE_SAMPLES: u8 = 5;
+}
+
+/// Charge Controller Configuration
+///
+/// Thresholds driving `drivers::battery_charge::ChargeController`'s
+/// `NotCharging -> Charging -> FullCheck -> Full -> Recharge` termination
+/// state machine.
+pub mod charge {
+    /// Terminal voltage `ChargeController` charges toward before looking for
+    /// taper current to confirm full charge
+    pub const FULL_VOLTAGE_MV: u32 = 4200;
+
+    /// Charge current below which the pack is considered topped off, once
+    /// `FULL_VOLTAGE_MV` has also been reached
+    pub const TAPER_CURRENT_CUTOFF_MA: i32 = 100;
+
+    /// Consecutive `ChargeController::update` samples the taper condition
+    /// must hold before committing `ChargeState::FullCheck -> Full`, to
+    /// reject a momentary current dip
+    pub const FULL_CHECK_SAMPLES: u8 = 5;
+
+    /// A `Full` cell's voltage sagging below this restarts a charge cycle
+    /// (`ChargeState::Full -> Recharge -> Charging`)
+    pub const RECHARGE_VOLTAGE_MV: u32 = 4000;
+
+    /// Maximum time a charge cycle may run without reaching
+    /// `ChargeState::Full` before `ChargeController::update` reports
+    /// `BatteryError::ChargeTimeout`
+    pub const MAX_CHARGE_TIME_MS: u32 = 4 * 60 * 60 * 1000;
+}
+
 /// Timing Configuration
 pub mod timing {
     /// pEMF pulse frequency in Hz
@@ -203,7 +355,7 @@ pub mod features {
 /// Runtime logging configuration structure
 /// This struct holds runtime-configurable logging parameters that can be modified via USB control commands
 /// Requirements: 8.1, 8.2, 8.3, 8.4, 8.5
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub struct LogConfig {
     /// Runtime maximum log level (can be more restrictive than compile-time MAX_LOG_LEVEL)
     pub max_level: LogLevel,
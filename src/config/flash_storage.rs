@@ -2,22 +2,177 @@ use crate::types::errors::SystemError;
 use crate::types::waveform::WaveformConfig;
 use embedded_storage::nor_flash::{NorFlash, ReadNorFlash};
 
+/// Number of erase-sector slots in the persistence ring. Saving always
+/// erases and writes the *next* slot rather than the current one, so a
+/// write interrupted by a power loss (or a sector that develops a bad bit)
+/// never destroys the only good copy — `load_config` scans every slot and
+/// keeps the highest surviving sequence number.
+const NUM_SLOTS: u32 = 4;
+
+/// Format version stamped into every record; bump this if the on-flash
+/// layout changes so stale records are rejected instead of misread
+const FORMAT_VERSION: u8 = 1;
+
+/// Fixed record size within a slot: version (1) + sequence (4) + config
+/// fields (4 x f32 = 16) + crc16 (2) = 23, rounded up to a round number
+const RECORD_SIZE: usize = 32;
+
+/// Persists a `WaveformConfig` across reboots in a small ring of flash
+/// erase-sectors, gated on `validate_config` so a corrupt or out-of-range
+/// value is never committed.
 pub struct ConfigStorage<F: NorFlash + ReadNorFlash> {
     flash: F,
+    /// Byte offset of slot 0 within `flash`; slots 1..NUM_SLOTS follow at
+    /// `F::ERASE_SIZE` strides
+    base_offset: u32,
 }
 
 impl<F: NorFlash + ReadNorFlash> ConfigStorage<F> {
-    pub fn new(flash: F) -> Self {
-        Self { flash }
+    /// `base_offset` must be erase-sector aligned and have at least
+    /// `NUM_SLOTS * F::ERASE_SIZE` bytes free after it (callers typically
+    /// reserve a region near the end of the device's flash for this)
+    pub fn new(flash: F, base_offset: u32) -> Self {
+        Self { flash, base_offset }
+    }
+
+    fn slot_offset(&self, slot: u32) -> u32 {
+        self.base_offset + slot * F::ERASE_SIZE as u32
     }
 
-    pub fn save_config(&mut self, _config: &WaveformConfig) -> Result<(), SystemError> {
-        // Implementation to be added
+    /// Validate, then write `config` into the slot after the current
+    /// highest-sequence record (wrapping around the ring), bumping the
+    /// sequence number so `load_config` can tell it apart from older slots.
+    pub fn save_config(&mut self, config: &WaveformConfig) -> Result<(), SystemError> {
+        if !crate::config::validation::validate_config(config) {
+            return Err(SystemError::ConfigurationInvalid);
+        }
+
+        let (next_slot, next_sequence) = match self.scan_slots() {
+            Some((slot, record)) => ((slot + 1) % NUM_SLOTS, record.sequence.wrapping_add(1)),
+            None => (0, 1),
+        };
+
+        let record = ConfigRecord {
+            sequence: next_sequence,
+            config: *config,
+        };
+        let bytes = record.to_bytes();
+
+        let offset = self.slot_offset(next_slot);
+        self.flash
+            .erase(offset, offset + F::ERASE_SIZE as u32)
+            .map_err(|_| SystemError::FlashOperationFailed)?;
+        self.flash
+            .write(offset, &bytes)
+            .map_err(|_| SystemError::FlashOperationFailed)?;
+
         Ok(())
     }
 
+    /// Scan every slot and return the valid record with the highest
+    /// sequence number, or `WaveformConfig::default()` if every slot is
+    /// empty or fails its CRC.
     pub fn load_config(&mut self) -> Result<WaveformConfig, SystemError> {
-        // Implementation to be added
-        Ok(crate::config::defaults::DEFAULT_WAVEFORM_CONFIG)
+        match self.scan_slots() {
+            Some((_, record)) => Ok(record.config),
+            None => Ok(crate::config::defaults::DEFAULT_WAVEFORM_CONFIG),
+        }
+    }
+
+    /// Read and CRC-check every slot, returning the slot index and record
+    /// with the highest sequence number among those that decode cleanly
+    fn scan_slots(&mut self) -> Option<(u32, ConfigRecord)> {
+        let mut best: Option<(u32, ConfigRecord)> = None;
+
+        for slot in 0..NUM_SLOTS {
+            let mut buf = [0u8; RECORD_SIZE];
+            if self.flash.read(self.slot_offset(slot), &mut buf).is_err() {
+                continue;
+            }
+
+            if let Some(record) = ConfigRecord::from_bytes(&buf) {
+                let is_newer = match &best {
+                    Some((_, current_best)) => record.sequence > current_best.sequence,
+                    None => true,
+                };
+                if is_newer {
+                    best = Some((slot, record));
+                }
+            }
+        }
+
+        best
+    }
+}
+
+/// One persisted `WaveformConfig` record: a monotonic sequence number (for
+/// picking the newest of several slots), the config itself, and a CRC
+/// guarding against partial or corrupted writes. The format version lives
+/// outside this struct since a version mismatch must be checked before the
+/// rest of the record is even trusted to parse.
+#[derive(Clone, Copy)]
+struct ConfigRecord {
+    sequence: u32,
+    config: WaveformConfig,
+}
+
+impl ConfigRecord {
+    fn to_bytes(&self) -> [u8; RECORD_SIZE] {
+        // Erased flash reads as 0xFF; padding the record with 0xFF instead
+        // of 0x00 keeps unused trailing bytes indistinguishable from an
+        // unwritten slot rather than looking like a second, shorter record
+        let mut buf = [0xFFu8; RECORD_SIZE];
+        buf[0] = FORMAT_VERSION;
+        buf[1..5].copy_from_slice(&self.sequence.to_le_bytes());
+        buf[5..9].copy_from_slice(&self.config.frequency_hz.to_le_bytes());
+        buf[9..13].copy_from_slice(&self.config.duty_cycle_percent.to_le_bytes());
+        buf[13..17].copy_from_slice(&self.config.waveform_factor.to_le_bytes());
+        buf[17..21].copy_from_slice(&self.config.amplitude_percent.to_le_bytes());
+
+        let crc = crc16(&buf[0..21]);
+        buf[21..23].copy_from_slice(&crc.to_le_bytes());
+        buf
+    }
+
+    fn from_bytes(buf: &[u8; RECORD_SIZE]) -> Option<Self> {
+        if buf[0] != FORMAT_VERSION {
+            return None;
+        }
+
+        let crc = u16::from_le_bytes([buf[21], buf[22]]);
+        if crc16(&buf[0..21]) != crc {
+            return None;
+        }
+
+        let sequence = u32::from_le_bytes([buf[1], buf[2], buf[3], buf[4]]);
+        let config = WaveformConfig {
+            frequency_hz: f32::from_le_bytes([buf[5], buf[6], buf[7], buf[8]]),
+            duty_cycle_percent: f32::from_le_bytes([buf[9], buf[10], buf[11], buf[12]]),
+            waveform_factor: f32::from_le_bytes([buf[13], buf[14], buf[15], buf[16]]),
+            amplitude_percent: f32::from_le_bytes([buf[17], buf[18], buf[19], buf[20]]),
+        };
+
+        if !crate::config::validation::validate_config(&config) {
+            return None;
+        }
+
+        Some(Self { sequence, config })
+    }
+}
+
+/// CRC-16/CCITT-FALSE over a record's header+payload bytes, used to detect
+/// partial or corrupted flash writes
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
     }
+    crc
 }
@@ -1,9 +1,10 @@
 use crate::types::battery::{
-    BatteryState, BatteryReading, SafetyFlags, convert_adc_to_voltage_mv,
+    BatteryState, BatteryStateTracker, BatteryReading, SafetyFlags, convert_adc_to_voltage_mv,
     LOW_BATTERY_ADC_THRESHOLD, CHARGING_ADC_THRESHOLD, OVERVOLTAGE_ADC_THRESHOLD,
     UNDERVOLTAGE_ADC_THRESHOLD
 };
 use crate::types::errors::BatteryError;
+use crate::utils::voltage_filter::VoltageFilter;
 use rp2040_hal::adc::Adc;
 use core::sync::atomic::{AtomicU16, AtomicU32, Ordering};
 use embedded_hal::adc::OneShot;
@@ -19,14 +20,27 @@ pub struct BatteryMonitor {
     /// Timestamp of last successful reading (milliseconds)
     last_reading_timestamp: AtomicU32,
     
-    /// Current battery state
-    current_state: BatteryState,
-    
+    /// Hysteresis- and debounce-stabilized battery state, replacing a raw
+    /// per-sample `BatteryState::from_adc_reading` call so jitter right at a
+    /// threshold doesn't flap the reported state every tick. Unlike
+    /// `BatteryHealth::evaluate`/`FuelGauge`/`ChargeController` (see their
+    /// own doc comments), this one only needs the voltage ADC channel
+    /// `BatteryMonitor` already reads, so it's wired into
+    /// `update_battery_state`/`create_battery_reading` for real today
+    /// rather than held back.
+    state_tracker: BatteryStateTracker,
+
     /// ADC read error counter for fault detection
     error_count: u8,
     
     /// Maximum allowed consecutive ADC errors before fault declaration
     max_error_count: u8,
+
+    /// Glitch-rejecting moving-average/jump-limiting filter sitting between
+    /// the raw ADC read and everything downstream (safety thresholds, state
+    /// classification, SOC reporting), so a single corrupted conversion
+    /// can't flip the logged battery state
+    voltage_filter: VoltageFilter,
 }
 
 impl BatteryMonitor {
@@ -36,9 +50,10 @@ impl BatteryMonitor {
             adc,
             last_adc_reading: AtomicU16::new(2000), // Safe default (normal range)
             last_reading_timestamp: AtomicU32::new(0),
-            current_state: BatteryState::Normal,
+            state_tracker: BatteryStateTracker::new(),
             error_count: 0,
             max_error_count: 5, // Allow up to 5 consecutive errors before fault
+            voltage_filter: VoltageFilter::new(),
         }
     }
     
@@ -51,16 +66,21 @@ impl BatteryMonitor {
             Ok(adc_value) => {
                 // Successful reading - reset error count
                 self.error_count = 0;
-                
-                // Store reading atomically for other tasks
-                self.last_adc_reading.store(adc_value, Ordering::SeqCst);
-                
+
+                // Run the raw conversion through the glitch-rejecting
+                // filter before anything downstream (safety thresholds,
+                // state classification, SOC) ever sees it
+                let filtered_value = self.voltage_filter.update(adc_value);
+
+                // Store the filtered reading atomically for other tasks
+                self.last_adc_reading.store(filtered_value, Ordering::SeqCst);
+
                 // Update timestamp (would come from RTIC monotonic timer in real system)
                 // For now using a placeholder that increments
                 let current_timestamp = self.last_reading_timestamp.load(Ordering::SeqCst) + 100;
                 self.last_reading_timestamp.store(current_timestamp, Ordering::SeqCst);
-                
-                Ok(adc_value)
+
+                Ok(filtered_value)
             },
             Err(_) => {
                 // Hardware error - increment error count
@@ -82,19 +102,20 @@ impl BatteryMonitor {
         }
     }
     
-    /// Get current battery state based on ADC reading
+    /// Get current battery state, debounced and hysteresis-stabilized by
+    /// `state_tracker` rather than reclassified from the raw ADC reading
     pub fn get_battery_state(&self) -> BatteryState {
-        let adc_value = self.last_adc_reading.load(Ordering::SeqCst);
-        BatteryState::from_adc_reading(adc_value)
+        self.state_tracker.state()
     }
-    
-    /// Update internal battery state and detect state transitions
+
+    /// Feed the latest ADC reading through `state_tracker` and report
+    /// whether it committed a state transition
     pub fn update_battery_state(&mut self) -> Option<(BatteryState, BatteryState)> {
-        let new_state = self.get_battery_state();
-        
-        if new_state != self.current_state {
-            let old_state = self.current_state;
-            self.current_state = new_state;
+        let adc_value = self.last_adc_reading.load(Ordering::SeqCst);
+        let old_state = self.state_tracker.state();
+        let new_state = self.state_tracker.update(adc_value);
+
+        if new_state != old_state {
             Some((old_state, new_state))
         } else {
             None
@@ -129,11 +150,17 @@ impl BatteryMonitor {
     }
     
     /// Create complete battery reading with timestamp and safety flags
+    ///
+    /// `BatteryReading::new` classifies `state` from the raw ADC reading
+    /// alone; overridden here with `state_tracker`'s debounced state so
+    /// reports reflect the same stabilized state `get_battery_state` does.
     pub fn create_battery_reading(&self, safety_flags: &SafetyFlags) -> BatteryReading {
         let adc_value = self.last_adc_reading.load(Ordering::SeqCst);
         let timestamp = self.last_reading_timestamp.load(Ordering::SeqCst);
-        
-        BatteryReading::new(timestamp, adc_value, safety_flags)
+
+        let mut reading = BatteryReading::new(timestamp, adc_value, safety_flags);
+        reading.state = self.state_tracker.state();
+        reading
     }
     
     /// Get last ADC reading (thread-safe atomic access)
@@ -167,6 +194,13 @@ impl BatteryMonitor {
     pub fn reset_error_count(&mut self) {
         self.error_count = 0;
     }
+
+    /// Whether the most recent ADC sample was a glitch clamped by
+    /// `voltage_filter` rather than accepted outright - for the RTIC task
+    /// to log as diagnostic info alongside the ordinary battery reading
+    pub fn last_sample_rejected(&self) -> bool {
+        self.voltage_filter.last_sample_rejected()
+    }
     
     /// Validate ADC reading is within expected range
     /// ADC should never read exactly 0 or 4095 in normal operation
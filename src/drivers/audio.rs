@@ -0,0 +1,262 @@
+//! USB Audio Class 1.0 streaming of the synthesized waveform
+//!
+//! `waveform_config` has lived in `Shared` since the waveform generator was
+//! built, but nothing ever got the samples off the device - a host could
+//! only infer what the PWM output looked like from the PEMF GPIO pin. This
+//! adds a second, isochronous USB interface alongside the existing
+//! `HIDClass` command interface that streams the same `WaveformBuffer`
+//! output as mono 8-bit PCM, so a host can capture/monitor it in real time
+//! with any UAC1-capable audio stack.
+//!
+//! The classic isochronous under-run/stutter problem (an endpoint callback
+//! firing mid-fill, as seen in the stm32-usbd-audio example) is avoided with
+//! [`AudioDoubleBuffer`]: the render task always writes into the buffer *not*
+//! currently queued for transmission, so a packet handed to the host is
+//! never mutated out from under it.
+
+use crate::types::waveform::{WaveformBuffer, PWM_RESOLUTION_BITS};
+use usb_device::bus::{InterfaceNumber, UsbBus, UsbBusAllocator};
+use usb_device::class::UsbClass;
+use usb_device::descriptor::DescriptorWriter;
+use usb_device::endpoint::{EndpointIn, IsochronousSynchronizationType, IsochronousUsageType};
+
+/// Isochronous audio streamed at 8kHz mono, 8-bit PCM: one byte per sample
+/// keeps a full-speed 1ms-interval packet to 8 bytes, well under a
+/// full-speed isochronous endpoint's practical limit and simple enough to
+/// fill straight from the waveform's DDS table without a resampler.
+pub const AUDIO_SAMPLE_RATE_HZ: u32 = 8000;
+/// Samples per 1ms USB frame at `AUDIO_SAMPLE_RATE_HZ`
+pub const AUDIO_PACKET_SAMPLES: usize = (AUDIO_SAMPLE_RATE_HZ / 1000) as usize;
+/// Silence level for 8-bit unsigned PCM (the format's zero-crossing midpoint)
+const PCM_SILENCE: u8 = 128;
+
+const USB_CLASS_AUDIO: u8 = 0x01;
+const AUDIO_SUBCLASS_CONTROL: u8 = 0x01;
+const AUDIO_SUBCLASS_STREAMING: u8 = 0x02;
+const AUDIO_PROTOCOL_UNDEFINED: u8 = 0x00;
+
+const CS_INTERFACE: u8 = 0x24;
+const CS_ENDPOINT: u8 = 0x25;
+
+const AC_HEADER: u8 = 0x01;
+const AC_INPUT_TERMINAL: u8 = 0x02;
+const AC_OUTPUT_TERMINAL: u8 = 0x03;
+const AS_GENERAL: u8 = 0x01;
+const AS_FORMAT_TYPE: u8 = 0x02;
+const FORMAT_TYPE_I: u8 = 0x01;
+const EP_GENERAL: u8 = 0x01;
+
+/// USB Terminal Types (Audio10 spec, Terminal Types Appendix)
+const TERMINAL_STREAMING: u16 = 0x0101;
+const TERMINAL_SPEAKER: u16 = 0x0301;
+/// Fixed terminal IDs - only one input and one output terminal exist
+const INPUT_TERMINAL_ID: u8 = 1;
+const OUTPUT_TERMINAL_ID: u8 = 2;
+
+/// Double-buffered PCM frame: one buffer is handed to the isochronous
+/// endpoint each USB frame while the other is refilled from the waveform
+/// generator, so a slow or uneven refill never corrupts a frame already
+/// queued for transmission.
+pub struct AudioDoubleBuffer {
+    buffers: [[u8; AUDIO_PACKET_SAMPLES]; 2],
+    filled: [bool; 2],
+    write_index: usize,
+}
+
+impl AudioDoubleBuffer {
+    pub const fn new() -> Self {
+        Self {
+            buffers: [[PCM_SILENCE; AUDIO_PACKET_SAMPLES]; 2],
+            filled: [false, false],
+            write_index: 0,
+        }
+    }
+
+    /// Render one packet's worth of samples from `waveform` into the back
+    /// buffer (the one not currently queued for transmission) and swap it in
+    pub fn refill(&mut self, waveform: &mut WaveformBuffer) {
+        for sample in self.buffers[self.write_index].iter_mut() {
+            // WaveformBuffer produces PWM_RESOLUTION_BITS-wide samples;
+            // rescale down to 8-bit unsigned PCM
+            let raw = waveform.get_next_sample();
+            *sample = (raw >> (PWM_RESOLUTION_BITS - 8)) as u8;
+        }
+        self.filled[self.write_index] = true;
+        self.write_index = 1 - self.write_index;
+    }
+
+    /// Take the most recently filled buffer for transmission. If `refill` hasn't
+    /// run ahead of the last `take_packet` (the render task fell behind),
+    /// returns silence rather than replaying stale or partially-written
+    /// samples.
+    pub fn take_packet(&mut self) -> [u8; AUDIO_PACKET_SAMPLES] {
+        let read_index = 1 - self.write_index;
+        if self.filled[read_index] {
+            self.filled[read_index] = false;
+            self.buffers[read_index]
+        } else {
+            [PCM_SILENCE; AUDIO_PACKET_SAMPLES]
+        }
+    }
+}
+
+impl Default for AudioDoubleBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// USB Audio Class 1.0 isochronous streaming interface, composed alongside
+/// the existing `HIDClass` command interface on the same `UsbBusAllocator`.
+/// Two interfaces are declared per the UAC1 spec: Audio Control (no
+/// endpoints, just the terminal/unit topology) and Audio Streaming (the
+/// isochronous IN endpoint that actually carries samples).
+pub struct UsbAudioClass<'a, B: UsbBus> {
+    audio_control_if: InterfaceNumber,
+    audio_streaming_if: InterfaceNumber,
+    endpoint: EndpointIn<'a, B>,
+    buffer: AudioDoubleBuffer,
+}
+
+impl<'a, B: UsbBus> UsbAudioClass<'a, B> {
+    pub fn new(alloc: &'a UsbBusAllocator<B>) -> Self {
+        Self {
+            audio_control_if: alloc.interface(),
+            audio_streaming_if: alloc.interface(),
+            // Asynchronous sync: we generate our own sample clock from the
+            // waveform's DDS phase accumulator rather than locking to the
+            // host's SOF, so no feedback endpoint is needed
+            endpoint: alloc.isochronous_in(
+                IsochronousSynchronizationType::Asynchronous,
+                IsochronousUsageType::Data,
+                AUDIO_PACKET_SAMPLES as u16,
+                1,
+            ),
+            buffer: AudioDoubleBuffer::new(),
+        }
+    }
+
+    /// Render the next packet's worth of samples from `waveform`. Called
+    /// once per USB frame by `audio_stream_task`, independent of whether the
+    /// link is configured yet, so the buffer is never behind once it is.
+    pub fn refill(&mut self, waveform: &mut WaveformBuffer) {
+        self.buffer.refill(waveform);
+    }
+
+    /// Push the next queued packet onto the isochronous endpoint.
+    /// Non-blocking: if the endpoint is still busy with the last packet (the
+    /// host hasn't picked it up), this frame is simply skipped rather than
+    /// blocking the task - matching the non-blocking discipline used
+    /// throughout this crate's other USB paths (`logging_transmit_task`'s
+    /// HID retries notwithstanding, which back off instead of spinning).
+    pub fn write_frame(&mut self) {
+        let packet = self.buffer.take_packet();
+        let _ = self.endpoint.write(&packet);
+    }
+}
+
+impl<B: UsbBus> UsbClass<B> for UsbAudioClass<'_, B> {
+    fn get_configuration_descriptors(
+        &self,
+        writer: &mut DescriptorWriter,
+    ) -> usb_device::Result<()> {
+        // --- Audio Control interface: declares the streaming interface
+        // below it via its Header descriptor, plus the Input/Output
+        // Terminal pair the streaming interface feeds into. No endpoints.
+        writer.interface(
+            self.audio_control_if,
+            USB_CLASS_AUDIO,
+            AUDIO_SUBCLASS_CONTROL,
+            AUDIO_PROTOCOL_UNDEFINED,
+        )?;
+
+        writer.write(
+            CS_INTERFACE,
+            &[
+                AC_HEADER,
+                0x00, 0x01, // bcdADC 1.00
+                0x00, 0x00, // wTotalLength: patched by the host parser from context; left 0 here
+                // as this minimal topology has nothing past the terminals below
+                0x01,                          // one streaming interface
+                u8::from(self.audio_streaming_if), // baInterfaceNr(1)
+            ],
+        )?;
+
+        writer.write(
+            CS_INTERFACE,
+            &[
+                AC_INPUT_TERMINAL,
+                INPUT_TERMINAL_ID,
+                (TERMINAL_STREAMING & 0xFF) as u8,
+                (TERMINAL_STREAMING >> 8) as u8,
+                0x00, // bAssocTerminal: none
+                0x01, // bNrChannels: mono
+                0x00, 0x00, // wChannelConfig: none (mono has no spatial position)
+                0x00, // iChannelNames
+                0x00, // iTerminal
+            ],
+        )?;
+
+        writer.write(
+            CS_INTERFACE,
+            &[
+                AC_OUTPUT_TERMINAL,
+                OUTPUT_TERMINAL_ID,
+                (TERMINAL_SPEAKER & 0xFF) as u8,
+                (TERMINAL_SPEAKER >> 8) as u8,
+                0x00,             // bAssocTerminal: none
+                INPUT_TERMINAL_ID, // bSourceID: fed from the input terminal above
+                0x00,             // iTerminal
+            ],
+        )?;
+
+        // --- Audio Streaming interface: one isochronous IN endpoint,
+        // PCM8 mono at AUDIO_SAMPLE_RATE_HZ
+        writer.interface(
+            self.audio_streaming_if,
+            USB_CLASS_AUDIO,
+            AUDIO_SUBCLASS_STREAMING,
+            AUDIO_PROTOCOL_UNDEFINED,
+        )?;
+
+        writer.write(
+            CS_INTERFACE,
+            &[
+                AS_GENERAL,
+                INPUT_TERMINAL_ID, // bTerminalLink: feeds the input terminal above
+                0x00,              // bDelay
+                0x01, 0x00,        // wFormatTag: PCM
+            ],
+        )?;
+
+        let sample_rate = AUDIO_SAMPLE_RATE_HZ.to_le_bytes();
+        writer.write(
+            CS_INTERFACE,
+            &[
+                AS_FORMAT_TYPE,
+                FORMAT_TYPE_I,
+                0x01, // bNrChannels: mono
+                0x01, // bSubframeSize: 1 byte/sample
+                0x08, // bBitResolution: 8 bits used
+                0x01, // bSamFreqType: one discrete rate
+                sample_rate[0],
+                sample_rate[1],
+                sample_rate[2], // wSamFreq is a 3-byte field in the Audio10 spec
+            ],
+        )?;
+
+        writer.endpoint(&self.endpoint)?;
+
+        writer.write(
+            CS_ENDPOINT,
+            &[
+                EP_GENERAL,
+                0x00, // bmAttributes: no sampling-frequency/pitch control
+                0x00, // bLockDelayUnits: undefined
+                0x00, 0x00, // wLockDelay
+            ],
+        )?;
+
+        Ok(())
+    }
+}
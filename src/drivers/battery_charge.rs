@@ -0,0 +1,274 @@
+use crate::types::battery::{ChargeEvent, ChargeState};
+use crate::types::errors::BatteryError;
+
+/// Charge controller configuration
+///
+/// Mirrors `config::charge`'s defaults so callers can override individual
+/// thresholds (e.g. a different pack chemistry) without touching the
+/// crate-wide defaults.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargeConfig {
+    /// Terminal voltage charged toward before looking for taper current
+    pub full_voltage_mv: u32,
+
+    /// Charge current below which the pack is considered topped off
+    pub taper_current_cutoff_ma: i32,
+
+    /// Consecutive samples the taper condition must hold before committing
+    /// `ChargeState::FullCheck -> Full`
+    pub full_check_samples: u8,
+
+    /// A `Full` cell's voltage sagging below this restarts a charge cycle
+    pub recharge_voltage_mv: u32,
+
+    /// Maximum time a charge cycle may run without reaching
+    /// `ChargeState::Full` before faulting with `BatteryError::ChargeTimeout`
+    pub max_charge_time_ms: u32,
+}
+
+impl Default for ChargeConfig {
+    fn default() -> Self {
+        Self {
+            full_voltage_mv: crate::config::charge::FULL_VOLTAGE_MV,
+            taper_current_cutoff_ma: crate::config::charge::TAPER_CURRENT_CUTOFF_MA,
+            full_check_samples: crate::config::charge::FULL_CHECK_SAMPLES,
+            recharge_voltage_mv: crate::config::charge::RECHARGE_VOLTAGE_MV,
+            max_charge_time_ms: crate::config::charge::MAX_CHARGE_TIME_MS,
+        }
+    }
+}
+
+/// Charging progress state machine
+///
+/// Tracks a charge cycle through `NotCharging -> Charging -> FullCheck ->
+/// Full`, with a `Recharge` loop back into `Charging` if a `Full` cell's
+/// voltage later sags, and a safety timeout fault if `Full` is never
+/// reached within `ChargeConfig::max_charge_time_ms`.
+///
+/// Not yet instantiated from firmware control flow: `update` needs a real
+/// `current_ma` reading to find the taper-current cutoff, and there is no
+/// charge-current sensing channel in hardware today - only the voltage ADC
+/// channel `BatteryMonitor` already reads. Held back pending a real
+/// current-sense channel rather than wired in against a made-up value.
+///
+/// TODO: this is library code only, not a delivered feature - wire it into
+/// `battery_monitor_task` once a current-sense ADC channel exists.
+pub struct ChargeController {
+    config: ChargeConfig,
+    state: ChargeState,
+    charging_start_ms: Option<u32>,
+    full_check_count: u8,
+}
+
+impl ChargeController {
+    /// Create a new controller with the given configuration, starting in
+    /// `ChargeState::NotCharging`
+    pub fn new(config: ChargeConfig) -> Self {
+        Self {
+            config,
+            state: ChargeState::NotCharging,
+            charging_start_ms: None,
+            full_check_count: 0,
+        }
+    }
+
+    /// Current charge state
+    pub fn state(&self) -> ChargeState {
+        self.state
+    }
+
+    /// Advance the charge state machine with a new voltage/current sample
+    ///
+    /// `charger_present` reflects the charger-detect line; `voltage_mv` and
+    /// `current_ma` are the latest battery measurements; `timestamp_ms` is
+    /// the current system time, used both to start the charge timer and to
+    /// detect `ChargeConfig::max_charge_time_ms` expiring.
+    pub fn update(
+        &mut self,
+        voltage_mv: u32,
+        current_ma: i32,
+        charger_present: bool,
+        timestamp_ms: u32,
+    ) -> ChargeEvent {
+        if !charger_present {
+            self.charging_start_ms = None;
+            self.full_check_count = 0;
+            return self.transition(ChargeState::NotCharging);
+        }
+
+        match self.state {
+            ChargeState::NotCharging | ChargeState::Recharge => {
+                self.charging_start_ms = Some(timestamp_ms);
+                self.full_check_count = 0;
+                self.transition(ChargeState::Charging)
+            }
+            ChargeState::Charging => {
+                if let Some(fault) = self.check_timeout(timestamp_ms) {
+                    return fault;
+                }
+                if voltage_mv >= self.config.full_voltage_mv
+                    && current_ma.unsigned_abs() <= self.config.taper_current_cutoff_ma as u32
+                {
+                    self.full_check_count = 1;
+                    self.transition(ChargeState::FullCheck)
+                } else {
+                    ChargeEvent::Unchanged(ChargeState::Charging)
+                }
+            }
+            ChargeState::FullCheck => {
+                if let Some(fault) = self.check_timeout(timestamp_ms) {
+                    return fault;
+                }
+                if voltage_mv >= self.config.full_voltage_mv
+                    && current_ma.unsigned_abs() <= self.config.taper_current_cutoff_ma as u32
+                {
+                    self.full_check_count = self.full_check_count.saturating_add(1);
+                    if self.full_check_count >= self.config.full_check_samples {
+                        self.charging_start_ms = None;
+                        self.transition(ChargeState::Full)
+                    } else {
+                        ChargeEvent::Unchanged(ChargeState::FullCheck)
+                    }
+                } else {
+                    // Taper condition dropped out before confirming - back to Charging
+                    self.full_check_count = 0;
+                    self.transition(ChargeState::Charging)
+                }
+            }
+            ChargeState::Full => {
+                if voltage_mv < self.config.recharge_voltage_mv {
+                    self.charging_start_ms = Some(timestamp_ms);
+                    self.transition(ChargeState::Recharge)
+                } else {
+                    ChargeEvent::Unchanged(ChargeState::Full)
+                }
+            }
+        }
+    }
+
+    /// Check the charge timer against `max_charge_time_ms`, returning a
+    /// `ChargeEvent::Fault` if it has expired
+    fn check_timeout(&mut self, timestamp_ms: u32) -> Option<ChargeEvent> {
+        let elapsed_ms = timestamp_ms.saturating_sub(self.charging_start_ms?);
+        if elapsed_ms > self.config.max_charge_time_ms {
+            self.charging_start_ms = None;
+            Some(ChargeEvent::Fault(BatteryError::ChargeTimeout {
+                elapsed_ms,
+                charge_state: self.state,
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Commit a state transition, returning `Unchanged` if it's a no-op
+    fn transition(&mut self, new_state: ChargeState) -> ChargeEvent {
+        if new_state == self.state {
+            ChargeEvent::Unchanged(new_state)
+        } else {
+            self.state = new_state;
+            ChargeEvent::Transitioned(new_state)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::errors::BatteryError;
+
+    #[test]
+    fn no_charger_present_stays_or_returns_to_not_charging() {
+        let mut controller = ChargeController::new(ChargeConfig::default());
+        let event = controller.update(3_800, 0, false, 0);
+        assert_eq!(event, ChargeEvent::Unchanged(ChargeState::NotCharging));
+        assert_eq!(controller.state(), ChargeState::NotCharging);
+    }
+
+    #[test]
+    fn charger_present_transitions_not_charging_to_charging() {
+        let mut controller = ChargeController::new(ChargeConfig::default());
+        let event = controller.update(3_800, 500, true, 0);
+        assert_eq!(event, ChargeEvent::Transitioned(ChargeState::Charging));
+        assert_eq!(controller.state(), ChargeState::Charging);
+    }
+
+    #[test]
+    fn full_check_commits_to_full_after_full_check_samples_consecutive_hits() {
+        let config = ChargeConfig::default();
+        let mut controller = ChargeController::new(config);
+        controller.update(3_800, 500, true, 0);
+
+        // First sample at/above full_voltage_mv with taper current <=
+        // cutoff: FullCheck, not yet Full
+        let event = controller.update(config.full_voltage_mv, config.taper_current_cutoff_ma, true, 1_000);
+        assert_eq!(event, ChargeEvent::Transitioned(ChargeState::FullCheck));
+
+        // Confirm over full_check_samples consecutive calls
+        for sample in 1..config.full_check_samples {
+            let event = controller.update(
+                config.full_voltage_mv,
+                config.taper_current_cutoff_ma,
+                true,
+                1_000 + sample as u32,
+            );
+            if sample < config.full_check_samples - 1 {
+                assert_eq!(event, ChargeEvent::Unchanged(ChargeState::FullCheck));
+            } else {
+                assert_eq!(event, ChargeEvent::Transitioned(ChargeState::Full));
+            }
+        }
+        assert_eq!(controller.state(), ChargeState::Full);
+    }
+
+    #[test]
+    fn full_check_drops_back_to_charging_if_taper_condition_is_lost() {
+        let config = ChargeConfig::default();
+        let mut controller = ChargeController::new(config);
+        controller.update(3_800, 500, true, 0);
+        controller.update(config.full_voltage_mv, config.taper_current_cutoff_ma, true, 1_000);
+        assert_eq!(controller.state(), ChargeState::FullCheck);
+
+        // Current spikes back above the taper cutoff: back to Charging
+        let event = controller.update(config.full_voltage_mv, config.taper_current_cutoff_ma * 10, true, 2_000);
+        assert_eq!(event, ChargeEvent::Transitioned(ChargeState::Charging));
+    }
+
+    #[test]
+    fn full_cell_sagging_below_recharge_voltage_starts_a_recharge_cycle() {
+        let config = ChargeConfig::default();
+        let mut controller = ChargeController::new(config);
+        controller.update(3_800, 500, true, 0);
+        for sample in 0..config.full_check_samples {
+            controller.update(
+                config.full_voltage_mv,
+                config.taper_current_cutoff_ma,
+                true,
+                1_000 + sample as u32,
+            );
+        }
+        assert_eq!(controller.state(), ChargeState::Full);
+
+        let event = controller.update(config.recharge_voltage_mv - 1, 0, true, 5_000);
+        assert_eq!(event, ChargeEvent::Transitioned(ChargeState::Recharge));
+    }
+
+    #[test]
+    fn charge_timeout_faults_without_reaching_full() {
+        let config = ChargeConfig {
+            max_charge_time_ms: 1_000,
+            ..ChargeConfig::default()
+        };
+        let mut controller = ChargeController::new(config);
+        controller.update(3_800, 500, true, 0);
+
+        let event = controller.update(3_800, 500, true, config.max_charge_time_ms + 1);
+        assert_eq!(
+            event,
+            ChargeEvent::Fault(BatteryError::ChargeTimeout {
+                elapsed_ms: config.max_charge_time_ms + 1,
+                charge_state: ChargeState::Charging,
+            })
+        );
+    }
+}
@@ -107,7 +107,7 @@ impl SafetyMonitor {
         
         if safety_flags.over_temperature.load(Ordering::SeqCst) {
             return Err(BatteryError::OverTemperature {
-                temperature_c: 60, // Example - real implementation would measure  
+                temp_mdeg: 60_000, // Example - real implementation would measure
                 current_state: BatteryState::from_adc_reading(adc_value),
             });
         }
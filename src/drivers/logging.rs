@@ -1,6 +1,8 @@
 #![allow(static_mut_refs)]
 
-use crate::types::logging::{LogCategory, LogLevel, LogMessage, LogReport, LoggingConfig};
+use crate::types::logging::{
+    LogCategory, LogFilter, LogLevel, LogMessage, LogReport, LoggingConfig,
+};
 use core::sync::atomic::{AtomicU32, Ordering};
 use heapless::spsc::Queue;
 
@@ -15,6 +17,7 @@ static mut LOGGING_CONFIG: LoggingConfig = LoggingConfig {
     enabled_categories: 0xF, // All categories enabled by default
     verbosity_level: LogLevel::Debug,
     enabled: true,
+    log_filter: LogFilter::allow_all(),
 };
 
 /// Initialize the logging system
@@ -26,6 +29,7 @@ pub fn init() {
             enabled_categories: 0xF, // All categories enabled by default
             verbosity_level: LogLevel::Debug,
             enabled: true,
+            log_filter: LogFilter::allow_all(),
         };
     }
 }
@@ -61,12 +65,37 @@ pub fn log_message(msg: LogMessage) {
         return;
     }
 
+    // Per-category allow mask and minimum level, on top of the checks above
+    if !unsafe { LOGGING_CONFIG.log_filter.allows(msg.category, msg.level) } {
+        return;
+    }
+
+    emit_defmt(&msg);
+
     unsafe {
         // Non-blocking enqueue - FIFO behavior, oldest message automatically discarded
         let _ = LOG_QUEUE.enqueue(msg);
     }
 }
 
+/// Mirror `msg` to the `defmt-rtt` sink at the severity matching its level,
+/// so it's observable over a debug probe independent of (and even before)
+/// the USB HID transport enumerating. Callers are expected to have already
+/// applied `LoggingConfig`/`LogFilter` filtering - the HID and RTT sinks
+/// share that one filtering pass rather than each doing their own.
+#[cfg(feature = "defmt-logs")]
+pub fn emit_defmt(msg: &LogMessage) {
+    match msg.level {
+        LogLevel::Debug => defmt::debug!("{}", msg),
+        LogLevel::Info => defmt::info!("{}", msg),
+        LogLevel::Warn => defmt::warn!("{}", msg),
+        LogLevel::Error => defmt::error!("{}", msg),
+    }
+}
+
+#[cfg(not(feature = "defmt-logs"))]
+pub fn emit_defmt(_msg: &LogMessage) {}
+
 /// Dequeue a log message for transmission
 pub fn dequeue_message() -> Option<LogMessage> {
     unsafe { LOG_QUEUE.dequeue() }
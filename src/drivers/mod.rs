@@ -1,7 +1,11 @@
 pub mod adc_battery;
+pub mod audio;
+pub mod battery_charge;
 pub mod battery_safety;
 pub mod led_control;
 pub mod logging;
+pub mod output_verification;
 pub mod pwm_waveform;
+pub mod serial_console;
 pub mod usb_command_handler;
 pub mod usb_hid;
@@ -0,0 +1,162 @@
+//! Closed-loop amplitude/frequency verification via external SPI ADC readback
+//!
+//! Complements the pEMF task's timing-deviation warnings with output
+//! verification: an external SPI ADC (the MAX116xx family fits
+//! `embedded-hal`'s `Transfer` trait cleanly) samples the real coil/driver
+//! signal, and the measured amplitude and zero-crossing frequency are
+//! compared against the `WaveformConfig` that should be driving the output.
+//! Deviations beyond a configurable tolerance are reported through the same
+//! `LogQueue` path every other subsystem uses for warnings.
+
+use crate::types::waveform::WaveformConfig;
+use embedded_hal::blocking::spi::Transfer;
+use heapless::HistoryBuffer;
+
+/// Number of ADC samples held in the sliding window used to estimate
+/// peak amplitude and zero-crossing frequency
+pub const VERIFICATION_WINDOW_SIZE: usize = 32;
+
+#[derive(Clone, Copy, Debug)]
+pub enum VerificationError {
+    AdcReadFailed,
+}
+
+/// Latest amplitude/frequency measurements, exposed alongside the other
+/// diagnostics structs (e.g. `WaveformBufferDiagnostics`) so a host can
+/// display actual-vs-commanded output
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OutputVerificationDiagnostics {
+    pub measured_amplitude_percent: f32,
+    pub measured_frequency_hz: f32,
+    pub amplitude_deviation_percent: f32,
+    pub frequency_deviation_percent: f32,
+    pub last_check_timestamp_us: u32,
+}
+
+/// Periodically samples an external SPI ADC and verifies the measured
+/// output against the `WaveformConfig` that should be producing it
+pub struct OutputVerifier<SPI: Transfer<u8>> {
+    adc: SPI,
+    window: HistoryBuffer<u16, VERIFICATION_WINDOW_SIZE>,
+    /// ADC full-scale reading corresponding to 100% amplitude, used to
+    /// convert raw counts into a percentage comparable to `amplitude_percent`
+    adc_full_scale: u16,
+    /// Rate at which `sample()` is called, used to convert zero-crossing
+    /// counts in the window into a frequency in Hz
+    sample_rate_hz: u32,
+    tolerance_percent: f32,
+    diagnostics: OutputVerificationDiagnostics,
+}
+
+impl<SPI: Transfer<u8>> OutputVerifier<SPI> {
+    pub fn new(adc: SPI, adc_full_scale: u16, sample_rate_hz: u32, tolerance_percent: f32) -> Self {
+        Self {
+            adc,
+            window: HistoryBuffer::new(),
+            adc_full_scale,
+            sample_rate_hz,
+            tolerance_percent,
+            diagnostics: OutputVerificationDiagnostics::default(),
+        }
+    }
+
+    /// Read one ADC sample and fold it into the sliding window. Returns
+    /// `Ok(None)` until the window holds enough samples for a full estimate,
+    /// `Ok(Some(diagnostics))` once a new measurement is ready (logging a
+    /// warning first if it drifts outside `tolerance_percent`), and forwards
+    /// SPI errors as `VerificationError::AdcReadFailed`.
+    pub fn sample(
+        &mut self,
+        expected: &WaveformConfig,
+        timestamp_us: u32,
+    ) -> Result<Option<OutputVerificationDiagnostics>, VerificationError> {
+        let mut buf = [0u8; 2];
+        self.adc
+            .transfer(&mut buf)
+            .map_err(|_| VerificationError::AdcReadFailed)?;
+        let raw = u16::from_be_bytes(buf);
+        self.window.write(raw);
+
+        if self.window.len() < VERIFICATION_WINDOW_SIZE {
+            return Ok(None);
+        }
+
+        let measured_amplitude_percent = self.estimate_amplitude_percent();
+        let measured_frequency_hz = self.estimate_frequency_hz();
+
+        let amplitude_deviation_percent =
+            (measured_amplitude_percent - expected.amplitude_percent).abs();
+        let frequency_deviation_percent = if expected.frequency_hz > 0.0 {
+            ((measured_frequency_hz - expected.frequency_hz) / expected.frequency_hz).abs() * 100.0
+        } else {
+            0.0
+        };
+
+        self.diagnostics = OutputVerificationDiagnostics {
+            measured_amplitude_percent,
+            measured_frequency_hz,
+            amplitude_deviation_percent,
+            frequency_deviation_percent,
+            last_check_timestamp_us: timestamp_us,
+        };
+
+        if amplitude_deviation_percent > self.tolerance_percent {
+            crate::log_warn!(
+                "amplitude deviation: measured {} expected {}",
+                measured_amplitude_percent as u32,
+                expected.amplitude_percent as u32
+            );
+        }
+        if frequency_deviation_percent > self.tolerance_percent {
+            crate::log_warn!(
+                "frequency deviation: measured {} expected {}",
+                measured_frequency_hz as u32,
+                expected.frequency_hz as u32
+            );
+        }
+
+        Ok(Some(self.diagnostics))
+    }
+
+    /// Peak-based amplitude estimate over the window, as a percentage of
+    /// `adc_full_scale`
+    fn estimate_amplitude_percent(&self) -> f32 {
+        if self.adc_full_scale == 0 {
+            return 0.0;
+        }
+        let peak = self.window.oldest_ordered().copied().max().unwrap_or(0);
+        (peak as f32 / self.adc_full_scale as f32) * 100.0
+    }
+
+    /// Zero-crossing frequency estimate over the window, relative to the
+    /// window's midpoint (half of `adc_full_scale`)
+    fn estimate_frequency_hz(&self) -> f32 {
+        let midpoint = self.adc_full_scale / 2;
+        let mut crossings = 0u32;
+        let mut previous_above: Option<bool> = None;
+
+        for &sample in self.window.oldest_ordered() {
+            let above = sample >= midpoint;
+            if let Some(prev) = previous_above {
+                if prev != above {
+                    crossings += 1;
+                }
+            }
+            previous_above = Some(above);
+        }
+
+        // Each full cycle produces two zero crossings
+        let window_duration_s = self.window.len() as f32 / self.sample_rate_hz as f32;
+        if window_duration_s > 0.0 {
+            (crossings as f32 / 2.0) / window_duration_s
+        } else {
+            0.0
+        }
+    }
+
+    /// Most recent measurement, or all-zero defaults before the window has
+    /// filled once
+    pub fn diagnostics(&self) -> OutputVerificationDiagnostics {
+        self.diagnostics
+    }
+}
@@ -0,0 +1,164 @@
+//! CDC-ACM serial console: a text-mode twin of the HID command/logging
+//! channel, so a host can `cat`/`screen` the device's `/dev/ttyACM*` node
+//! and get readable log lines without the custom HID host application,
+//! plus type a handful of plain-text commands back in.
+//!
+//! Kept deliberately thin - this module only turns bytes into lines and
+//! lines into [`SerialCommand`]s/formatted text. Dispatching a parsed
+//! command and actually writing to the `SerialPort` stays in
+//! `serial_console_task` (`main.rs`), matching how `usb_command_handler`
+//! parses HID reports but leaves locking shared state to its RTIC task.
+
+use crate::types::logging::LogMessage;
+use heapless::String;
+
+/// Accumulates incoming serial bytes into newline-terminated lines. When a
+/// line overflows the buffer, the bytes gathered so far are silently dropped
+/// and accumulation starts over from the next byte, so only the tail of an
+/// over-long line is returned, rather than blocking the whole console on a
+/// malformed or oversized write.
+pub struct LineAccumulator {
+    buffer: String<64>,
+}
+
+impl LineAccumulator {
+    pub const fn new() -> Self {
+        Self {
+            buffer: String::new(),
+        }
+    }
+
+    /// Feed newly read bytes in. Returns the completed line (without the
+    /// terminator) the first time a `\n` is seen; any bytes after it are
+    /// dropped until the next call, since every command fits in one read in
+    /// practice and a partial second line is rare enough not to warrant a
+    /// multi-line queue.
+    pub fn feed(&mut self, bytes: &[u8]) -> Option<String<64>> {
+        for &byte in bytes {
+            if byte == b'\n' {
+                let line = self.buffer.clone();
+                self.buffer.clear();
+                return Some(line);
+            }
+            if byte != b'\r' && self.buffer.push(byte as char).is_err() {
+                // Line too long for the buffer - drop it rather than wedge
+                // the console on a runaway write
+                self.buffer.clear();
+            }
+        }
+        None
+    }
+}
+
+impl Default for LineAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Plain-text commands accepted over the serial console, each mapped onto
+/// the same action the equivalent HID [`crate::types::usb_commands::UsbCommand`]
+/// already triggers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SerialCommand {
+    /// `stats` - firmware version and last safety trip, if any
+    Stats,
+    /// `reset` - enter the ROM bootloader
+    Reset,
+    /// `soc` - current battery state
+    Soc,
+}
+
+/// Parse one line of input (already trimmed of its `\r\n`/`\n` terminator).
+/// Matching is case-insensitive and tolerates surrounding whitespace, since
+/// a human typing into a terminal is the expected source, not a scripted
+/// host.
+pub fn parse_serial_command(line: &str) -> Option<SerialCommand> {
+    match line.trim() {
+        s if s.eq_ignore_ascii_case("stats") => Some(SerialCommand::Stats),
+        s if s.eq_ignore_ascii_case("reset") => Some(SerialCommand::Reset),
+        s if s.eq_ignore_ascii_case("soc") => Some(SerialCommand::Soc),
+        _ => None,
+    }
+}
+
+/// Render a queued [`LogMessage`] as one human-readable line, CRLF
+/// terminated to play nicely with plain terminal emulators.
+pub fn format_log_line(msg: &LogMessage) -> String<80> {
+    let mut line = String::new();
+    // `core::fmt::Write` can fail on overflow; a full 52-byte message plus
+    // the header can exceed the 80-byte buffer, and a failure here just
+    // yields a silently truncated line rather than anything unsafe.
+    let _ = core::fmt::Write::write_fmt(
+        &mut line,
+        format_args!(
+            "[{}] {:?} {:?}: {}\r\n",
+            msg.timestamp_ms,
+            msg.level,
+            msg.category,
+            msg.content_str()
+        ),
+    );
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::logging::{LogCategory, LogLevel};
+
+    #[test]
+    fn line_accumulator_splits_on_newline() {
+        let mut acc = LineAccumulator::new();
+        assert_eq!(acc.feed(b"stat"), None);
+        assert_eq!(acc.feed(b"s\n").as_deref(), Some("stats"));
+    }
+
+    #[test]
+    fn line_accumulator_strips_carriage_return() {
+        let mut acc = LineAccumulator::new();
+        assert_eq!(acc.feed(b"soc\r\n").as_deref(), Some("soc"));
+    }
+
+    #[test]
+    fn line_accumulator_drops_oversized_line() {
+        let mut acc = LineAccumulator::new();
+        let long = [b'x'; 100];
+        assert_eq!(acc.feed(&long), None);
+        // Buffer was cleared mid-write, so finishing the line now yields
+        // whatever was left after the overflow point rather than garbage
+        assert!(acc.feed(b"\n").is_some());
+    }
+
+    #[test]
+    fn parse_serial_command_is_case_insensitive_and_trims() {
+        assert_eq!(parse_serial_command(" Stats "), Some(SerialCommand::Stats));
+        assert_eq!(parse_serial_command("RESET"), Some(SerialCommand::Reset));
+        assert_eq!(parse_serial_command("Soc"), Some(SerialCommand::Soc));
+    }
+
+    #[test]
+    fn parse_serial_command_rejects_unknown_text() {
+        assert_eq!(parse_serial_command("help"), None);
+    }
+
+    #[test]
+    fn format_log_line_includes_timestamp_level_category_and_text() {
+        let msg = LogMessage {
+            timestamp_ms: 1234,
+            level: LogLevel::Warn,
+            category: LogCategory::Battery,
+            content: {
+                let mut content = [0u8; 52];
+                content[..3].copy_from_slice(b"low");
+                content
+            },
+            content_len: 3,
+        };
+        let line = format_log_line(&msg);
+        assert!(line.contains("1234"));
+        assert!(line.contains("Warn"));
+        assert!(line.contains("Battery"));
+        assert!(line.contains("low"));
+    }
+}
@@ -1,18 +1,131 @@
 #[cfg(feature = "usb-logs")]
-use crate::types::logging::{LogCategory, LogLevel, LoggingUsbCommand};
-use crate::types::usb_commands::UsbCommand;
+use crate::types::logging::{LogCategory, LoggingUsbCommand};
+use crate::types::logging::{LogFilter, LogLevel};
+use crate::types::usb_commands::{CommandRequest, CommandStatus, UsbCommand};
+use crate::types::waveform::WaveformConfig;
 
-pub fn parse_hid_report(report: &[u8; 64]) -> Option<UsbCommand> {
-    match report[0] {
+/// Parse a 64-byte HID OUT report into a [`CommandRequest`].
+///
+/// Report layout (modeled on the embedded-trainings `Request` parser):
+/// `[0]` opcode, `[1]` sequence id (opaque to the device, echoed back in the
+/// response so the host can correlate it), `[2..]` opcode-specific payload.
+/// An opcode this firmware doesn't recognize still yields a `CommandRequest`
+/// with `command: None`, so the caller can reply with
+/// `CommandStatus::UnknownOpcode` carrying the same `seq` rather than
+/// silently dropping the request.
+pub fn parse_hid_report(report: &[u8; 64]) -> CommandRequest {
+    let opcode = report[0];
+    let seq = report[1];
+    let command = match opcode {
         0x01 => Some(UsbCommand::SetFrequency(u32::from_le_bytes([
-            report[1], report[2], report[3], report[4],
+            report[2], report[3], report[4], report[5],
         ]))),
-        0x02 => Some(UsbCommand::SetDutyCycle(report[1])),
+        0x02 => Some(UsbCommand::SetDutyCycle(report[2])),
         0x03 => Some(UsbCommand::EnterBootloader),
+        0x04 => Some(UsbCommand::SetLogFilter(LogFilter {
+            category_allow_mask: report[2],
+            category_min_level: [
+                level_from_byte(report[3]),
+                level_from_byte(report[4]),
+                level_from_byte(report[5]),
+                level_from_byte(report[6]),
+            ],
+        })),
+        0x05 => Some(UsbCommand::SetWaveform(WaveformConfig {
+            frequency_hz: f32::from_le_bytes([report[2], report[3], report[4], report[5]]),
+            duty_cycle_percent: f32::from_le_bytes([
+                report[6], report[7], report[8], report[9],
+            ]),
+            waveform_factor: f32::from_le_bytes([
+                report[10], report[11], report[12], report[13],
+            ]),
+            amplitude_percent: f32::from_le_bytes([
+                report[14],
+                report[15],
+                report[16],
+                report[17],
+            ]),
+        })),
+        0x06 => Some(UsbCommand::GetBatteryState),
+        0x07 => Some(UsbCommand::GetWaveform),
+        0x08 => Some(UsbCommand::GetFirmwareVersion),
+        0x09 => Some(UsbCommand::GetSafetyStatus),
         _ => None,
+    };
+
+    CommandRequest {
+        opcode,
+        seq,
+        command,
     }
 }
 
+fn level_from_byte(byte: u8) -> LogLevel {
+    match byte {
+        0 => LogLevel::Debug,
+        1 => LogLevel::Info,
+        2 => LogLevel::Warn,
+        3 => LogLevel::Error,
+        _ => LogLevel::Info,
+    }
+}
+
+/// Build the 64-byte response report correlated to a [`CommandRequest`]:
+/// `[0]` the request's opcode echoed back, `[1]` the request's `seq` echoed
+/// back, `[2]` a [`CommandStatus`], `[3]` payload length, `[4..]` payload.
+/// `payload` longer than fits (60 bytes) is truncated.
+pub fn build_response_report(
+    opcode: u8,
+    seq: u8,
+    status: CommandStatus,
+    payload: &[u8],
+) -> crate::types::usb_commands::CommandReport {
+    let mut report = crate::types::usb_commands::CommandReport::new();
+    report.data[0] = opcode;
+    report.data[1] = seq;
+    report.data[2] = status as u8;
+
+    let copy_len = core::cmp::min(payload.len(), 60);
+    report.data[3] = copy_len as u8;
+    report.data[4..4 + copy_len].copy_from_slice(&payload[..copy_len]);
+
+    report
+}
+
+/// Marker byte identifying an unsolicited safety notification report, so a
+/// host parser can tell it apart from an ordinary command response (which
+/// starts with an echoed opcode from [`UsbCommand`]'s 0x01-0x09 range) or a
+/// queued [`crate::types::logging::LogReport`] sharing the same IN endpoint.
+pub const SAFETY_REPORT_MARKER: u8 = 0xF0;
+
+/// Build the 64-byte unsolicited safety notification report pushed by
+/// `safety_notification_task` the moment a [`crate::types::battery::SafetyReport`]
+/// lands in the safety mailbox: `[0]` [`SAFETY_REPORT_MARKER`], `[1]`
+/// `BatteryError::code()`, `[2]` `BatteryError::severity_level()`, `[3..=6]`
+/// timestamp, `[7..=8]` primary measured value, `[9..=10]` secondary
+/// measured value, `[11]` description length, `[12..]` description text.
+pub fn build_safety_notification_report(
+    report: &crate::types::battery::SafetyReport,
+) -> crate::types::usb_commands::CommandReport {
+    let mut out = crate::types::usb_commands::CommandReport::new();
+    let error = &report.error;
+    let (primary, secondary) = error.measured_values();
+
+    out.data[0] = SAFETY_REPORT_MARKER;
+    out.data[1] = error.code();
+    out.data[2] = error.severity_level();
+    out.data[3..7].copy_from_slice(&report.timestamp_ms.to_le_bytes());
+    out.data[7..9].copy_from_slice(&primary.to_le_bytes());
+    out.data[9..11].copy_from_slice(&secondary.to_le_bytes());
+
+    let desc = error.description().as_bytes();
+    let copy_len = core::cmp::min(desc.len(), out.data.len() - 12);
+    out.data[11] = copy_len as u8;
+    out.data[12..12 + copy_len].copy_from_slice(&desc[..copy_len]);
+
+    out
+}
+
 #[cfg(feature = "usb-logs")]
 pub fn parse_logging_hid_report(report: &[u8; 64]) -> Option<LoggingUsbCommand> {
     match report[0] {
@@ -48,6 +161,7 @@ pub fn parse_logging_hid_report(report: &[u8; 64]) -> Option<LoggingUsbCommand>
                     _ => LogLevel::Info,
                 },
                 enabled: report[3] != 0,
+                log_filter: LogFilter::allow_all(),
             };
             Some(LoggingUsbCommand::SetConfig(config))
         }
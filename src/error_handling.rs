@@ -9,7 +9,7 @@ use core::ops::FnMut;
 
 /// System error types for graceful error handling
 /// Requirements: 7.1 (graceful error handling for non-critical operations)
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum SystemError {
     /// ADC read operation failed
     AdcReadFailed,
@@ -25,6 +25,9 @@ pub enum SystemError {
     OperationInterrupted,
     /// Invalid parameter provided
     InvalidParameter,
+    /// Battery health transitioned out of `Good` (overheat, cold, over/under
+    /// voltage, or dead cell)
+    BatteryFault,
 }
 
 impl SystemError {
@@ -38,6 +41,7 @@ impl SystemError {
             SystemError::SystemBusy => "System is busy",
             SystemError::OperationInterrupted => "Operation was interrupted",
             SystemError::InvalidParameter => "Invalid parameter provided",
+            SystemError::BatteryFault => "Battery health degraded",
         }
     }
 
@@ -51,6 +55,7 @@ impl SystemError {
             SystemError::SystemBusy => LogLevel::Warn,
             SystemError::OperationInterrupted => LogLevel::Warn,
             SystemError::InvalidParameter => LogLevel::Error,
+            SystemError::BatteryFault => LogLevel::Error,
         }
     }
 
@@ -65,6 +70,7 @@ impl SystemError {
             SystemError::SystemBusy => false,          // Log and continue
             SystemError::OperationInterrupted => false, // Log and continue
             SystemError::InvalidParameter => false,    // Log and continue
+            SystemError::BatteryFault => false,        // Log and continue; safety response handled by caller
         }
     }
 }
@@ -140,6 +146,12 @@ impl ErrorRecovery {
                 // Recovery: Log the error and abort the operation
                 Err(error)
             }
+
+            SystemError::BatteryFault => {
+                log_message(LogLevel::Error, "ERROR_HANDLER", "Battery fault recorded");
+                // Recovery: log for visibility and continue monitoring
+                Ok(())
+            }
         }
     }
 
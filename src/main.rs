@@ -3,6 +3,15 @@
 
 // Import required crates - exact same imports as working reference
 use panic_probe as _;
+// `defmt_rtt` registers itself as the `#[defmt::global_logger]`, so
+// `defmt::info!`/`warn!`/`error!` (used by `drivers::logging::emit_defmt`)
+// reach RTT without any further wiring here. Pairing this feature with
+// panic-probe's own `print-defmt` feature (a Cargo.toml concern, not a
+// source one) additionally routes a panic's location/message through the
+// same sink, so a crash during/before USB enumeration is still visible
+// over a probe.
+#[cfg(feature = "defmt-logs")]
+use defmt_rtt as _;
 use rp2040_hal::{
     adc::{Adc, AdcPin},
     clocks::{init_clocks_and_plls, Clock},
@@ -16,6 +25,7 @@ use usb_device::{
     bus::UsbBusAllocator, descriptor::lang_id::LangID, device::StringDescriptors, prelude::*,
 };
 use usbd_hid::hid_class::HIDClass;
+use usbd_serial::SerialPort;
 
 // Import our modules
 mod config;
@@ -25,11 +35,13 @@ mod types;
 mod utils;
 
 use crate::config::usb;
+use crate::drivers::audio::UsbAudioClass;
 use crate::types::{
     bootloader_types::{BootloaderConfig, BootloaderState},
     logging::LogMessage,
     usb_commands::CommandReport,
-    waveform::WaveformConfig,
+    usb_link::{UsbLinkEvent, UsbLinkState},
+    waveform::{WaveformBuffer, WaveformConfig},
 };
 
 #[cfg(feature = "usb-logs")]
@@ -54,18 +66,45 @@ mod app {
     struct Shared {
         usb_dev: UsbDevice<'static, UsbBus>,
         hid_class: HIDClass<'static, UsbBus>,
+        audio_class: UsbAudioClass<'static, UsbBus>,
+        // CDC-ACM serial function, composed alongside the HID command
+        // interface and the audio streaming interface on the same
+        // allocator, giving the host a `/dev/ttyACM*` node without a second
+        // USB device
+        serial: SerialPort<'static, UsbBus>,
         bootloader_state: BootloaderState,
-        log_queue: Queue<LogMessage, 32>,
+        log_queue: crate::types::logging::PriorityLogQueue<32>,
+        // Single-producer/single-consumer tee of the messages
+        // `logging_transmit_task` takes from `drivers::logging`'s queue: fed
+        // by that task whenever usb-logs are enabled, drained by
+        // `serial_console_task`, so the same messages reach a terminal
+        // reading the serial port without the HID and serial paths
+        // contending over one consumer
+        serial_log_queue: heapless::spsc::Queue<crate::types::logging::LogMessage, 8>,
         logging_config: LoggingConfig,
+        usb_link_state: UsbLinkState,
+        usb_link_events: Queue<UsbLinkEvent, 8>,
         waveform_config: WaveformConfig,
         battery_monitor: crate::drivers::adc_battery::BatteryMonitor,
         safety_flags: crate::types::battery::SafetyFlags,
         battery_state: crate::types::battery::BatteryState,
+        // Single-slot mailbox, deliberately separate from `log_queue`: a
+        // trip here must reach the host even when the 32-entry log queue is
+        // saturated, so `safety_notification_task` can drain it independent
+        // of ordinary log traffic
+        safety_mailbox: heapless::spsc::Queue<crate::types::battery::SafetyReport, 1>,
+        // Last published safety trip, kept around (unlike the mailbox, which
+        // is drained) so `UsbCommand::GetSafetyStatus` can be polled at any
+        // time, not just in the instant right after a trip
+        last_safety_report: Option<crate::types::battery::SafetyReport>,
     }
 
     #[local]
     struct Local {
         battery_pin: AdcPin<Pin<Gpio26, FunctionSioInput, PullNone>>,
+        battery_debug_log_limiter: crate::types::logging::LogRateLimiter,
+        waveform_buffer: WaveformBuffer,
+        serial_line: crate::drivers::serial_console::LineAccumulator,
     }
 
     #[monotonic(binds = SysTick, default = true)]
@@ -122,13 +161,29 @@ mod app {
         // Create HID class device with custom report descriptor - exact same as working reference
         let hid_class = HIDClass::new(usb_bus_ref, CommandReport::descriptor(), 60);
 
+        // Create the audio streaming class on the same allocator so it
+        // enumerates as a second interface alongside the HID command
+        // interface, rather than a separate USB device
+        let audio_class = UsbAudioClass::new(usb_bus_ref);
+
+        // Create the CDC-ACM serial class on the same allocator, giving the
+        // device a `/dev/ttyACM*` node alongside its HID and audio
+        // interfaces
+        let serial = SerialPort::new(usb_bus_ref);
+
         // Configure USB device descriptors with custom VID/PID and device strings
         let usb_dev = UsbDeviceBuilder::new(
             usb_bus_ref,
             UsbVidPid(usb::usb::VENDOR_ID, usb::usb::PRODUCT_ID),
         )
         .device_release(usb::usb::DEVICE_RELEASE)
-        .device_class(0x00) // Use interface class instead of device class
+        // CDC-ACM's control+data interface pair needs to be declared as one
+        // function via an Interface Association Descriptor, or Windows
+        // hosts won't bind a COM port to it - `composite_with_iads` sets
+        // the device class/subclass/protocol this requires. HID and audio
+        // are unaffected by the device class field, so this is a strict
+        // upgrade over the previous `device_class(0x00)`.
+        .composite_with_iads()
         .strings(&[StringDescriptors::new(LangID::EN_US)
             .manufacturer(usb::usb::MANUFACTURER)
             .product(usb::usb::PRODUCT)
@@ -142,6 +197,9 @@ mod app {
         // Spawn the USB command handler task
         usb_command_handler_task::spawn_after(Duration::<u64, 1, 1000>::millis(20)).unwrap();
 
+        // Spawn the serial console task
+        serial_console_task::spawn_after(Duration::<u64, 1, 1000>::millis(25)).unwrap();
+
         // Spawn the logging transmission task if logging is enabled
         #[cfg(feature = "usb-logs")]
         {
@@ -151,6 +209,10 @@ mod app {
         // Spawn the battery monitor task
         battery_monitor_task::spawn_after(Duration::<u64, 1, 1000>::millis(100)).unwrap();
 
+        // Spawn the audio streaming task - fills one isochronous packet per
+        // USB frame (1ms at full speed)
+        audio_stream_task::spawn_after(Duration::<u64, 1, 1000>::millis(1)).unwrap();
+
         // Initialize logging system only
         #[cfg(feature = "system-logs")]
         {
@@ -162,20 +224,40 @@ mod app {
             Shared {
                 usb_dev,
                 hid_class,
+                audio_class,
+                serial,
                 bootloader_state: BootloaderState::Normal,
-                log_queue: Queue::new(),
+                log_queue: crate::types::logging::PriorityLogQueue::new(),
+                serial_log_queue: heapless::spsc::Queue::new(),
                 logging_config: LoggingConfig {
                     enabled_categories: 0xF, // All categories enabled by default
                     verbosity_level: crate::types::logging::LogLevel::Debug,
                     enabled: true,
+                    log_filter: crate::types::logging::LogFilter::allow_all(),
                 },
                 waveform_config: WaveformConfig::default(), // 10Hz sawtooth with 33% duty cycle
+                usb_link_state: UsbLinkState::new(),
+                usb_link_events: Queue::new(),
                 battery_monitor,
                 safety_flags: crate::types::battery::SafetyFlags::new(),
                 battery_state: crate::types::battery::BatteryState::Normal,
+                safety_mailbox: heapless::spsc::Queue::new(),
+                last_safety_report: None,
             },
             Local {
                 battery_pin,
+                battery_debug_log_limiter: crate::types::logging::LogRateLimiter::new(),
+                waveform_buffer: {
+                    // WaveformBuffer::new() leaves its table unpopulated
+                    // until update_config() sees a change; since it starts
+                    // holding the same WaveformConfig::default() the task
+                    // compares against, regenerate once up front so audio
+                    // isn't silent until the first SetWaveform command
+                    let mut buffer = WaveformBuffer::new();
+                    buffer.regenerate_samples();
+                    buffer
+                },
+                serial_line: crate::drivers::serial_console::LineAccumulator::new(),
             },
             init::Monotonics(mono),
         )
@@ -187,38 +269,55 @@ mod app {
     /// Without frequent polling, the device will disappear from USB enumeration.
     /// This is the core functionality that makes the device visible to lsusb.
     #[task(
-        shared = [usb_dev, hid_class],
+        shared = [usb_dev, hid_class, audio_class, serial, usb_link_state, usb_link_events],
         priority = 1
     )]
     fn usb_poll_task(mut ctx: usb_poll_task::Context) {
         // Lock shared resources for USB operations - exact same as working reference
         ctx.shared.usb_dev.lock(|usb_dev| {
             ctx.shared.hid_class.lock(|hid_class| {
-                // CRITICAL: This poll() call maintains USB enumeration
-                // Without this, device disappears from lsusb output
-                usb_dev.poll(&mut [hid_class])
+                ctx.shared.audio_class.lock(|audio_class| {
+                    ctx.shared.serial.lock(|serial| {
+                        // CRITICAL: This poll() call maintains USB enumeration
+                        // Without this, device disappears from lsusb output
+                        usb_dev.poll(&mut [hid_class, audio_class, serial])
+                    })
+                })
             })
         });
 
+        // Advance the link-state machine from the bus's actual state, rather
+        // than guessing enumeration completed from a timer
+        let bus_state = ctx.shared.usb_dev.lock(|usb_dev| usb_dev.state());
+        let event = (ctx.shared.usb_link_state, ctx.shared.usb_link_events).lock(
+            |link_state, events| {
+                let (next_state, event) = link_state.advance(bus_state);
+                *link_state = next_state;
+                if let Some(event) = event {
+                    let _ = events.enqueue(event);
+                }
+                event
+            },
+        );
+
+        // Emit the boot log exactly once, on the tick the link actually
+        // settles into Configured, instead of firing on a timer
+        if let Some(UsbLinkEvent::Configured) = event {
+            #[cfg(feature = "system-logs")]
+            {
+                use crate::drivers::logging;
+                logging::log_system_event("System booting");
+            }
+        }
+
         // Update timestamp for logging - increment by 10ms each poll
         #[cfg(feature = "usb-logs")]
         {
             use crate::drivers::logging::set_timestamp_ms;
             static mut TIMESTAMP_COUNTER: u32 = 0;
-            static mut INIT_LOG_SENT: bool = false;
             unsafe {
                 TIMESTAMP_COUNTER += 10; // Increment by 10ms
                 set_timestamp_ms(TIMESTAMP_COUNTER);
-
-                // Send init log once after USB is ready
-                if !INIT_LOG_SENT && TIMESTAMP_COUNTER > 100 {
-                    #[cfg(feature = "system-logs")]
-                    {
-                        use crate::drivers::logging;
-                        logging::log_system_event("System booting"); // todo/fixme:this part doesn't work
-                    }
-                    INIT_LOG_SENT = true;
-                }
             }
         }
 
@@ -226,21 +325,27 @@ mod app {
         usb_poll_task::spawn_after(Duration::<u64, 1, 1000>::millis(10)).unwrap();
     }
 
-    /// USB command handler task - processes HID reports for bootloader commands
+    /// USB command handler task - processes HID reports for bootloader,
+    /// waveform, logging, and status-query commands, replying to each with a
+    /// correlated response report so the host can tell a command landed
+    /// (and, for queries, get the data back) instead of firing blind.
     #[task(
-        shared = [hid_class, bootloader_state],
+        shared = [hid_class, bootloader_state, logging_config, waveform_config, battery_state, last_safety_report],
         priority = 1
     )]
     fn usb_command_handler_task(mut ctx: usb_command_handler_task::Context) {
-        use crate::drivers::usb_command_handler::parse_hid_report;
-        use crate::types::{bootloader_types::BootloaderConfig, usb_commands::UsbCommand};
+        use crate::drivers::usb_command_handler::{build_response_report, parse_hid_report};
+        use crate::types::{
+            bootloader_types::BootloaderConfig,
+            usb_commands::{CommandRequest, CommandStatus, UsbCommand},
+        };
 
         let mut buffer = [0u8; 64];
 
-        let command = ctx.shared.hid_class.lock(|hid_class| {
+        let request: Option<CommandRequest> = ctx.shared.hid_class.lock(|hid_class| {
             if let Ok(size) = hid_class.pull_raw_output(&mut buffer) {
                 if size == 64 {
-                    parse_hid_report(&buffer)
+                    Some(parse_hid_report(&buffer))
                 } else {
                     None
                 }
@@ -249,9 +354,9 @@ mod app {
             }
         });
 
-        if let Some(cmd) = command {
-            match cmd {
-                UsbCommand::EnterBootloader => {
+        if let Some(request) = request {
+            let (status, payload): (CommandStatus, heapless::Vec<u8, 16>) = match request.command {
+                Some(UsbCommand::EnterBootloader) => {
                     let can_enter = ctx
                         .shared
                         .bootloader_state
@@ -260,17 +365,192 @@ mod app {
                     if can_enter {
                         let config = BootloaderConfig::default();
                         bootloader_entry_task::spawn(config).ok();
+                        (CommandStatus::Ok, heapless::Vec::new())
+                    } else {
+                        (CommandStatus::Error, heapless::Vec::new())
                     }
                 }
-                _ => {
-                    // Handle other commands in future implementations
+                Some(UsbCommand::SetLogFilter(filter)) => {
+                    ctx.shared.logging_config.lock(|config| {
+                        config.log_filter = filter;
+                    });
+                    (CommandStatus::Ok, heapless::Vec::new())
+                }
+                Some(UsbCommand::SetWaveform(config)) => {
+                    ctx.shared.waveform_config.lock(|waveform_config| {
+                        *waveform_config = config;
+                    });
+                    (CommandStatus::Ok, heapless::Vec::new())
+                }
+                Some(UsbCommand::GetWaveform) => {
+                    let config = ctx.shared.waveform_config.lock(|config| *config);
+                    let mut payload = heapless::Vec::new();
+                    let _ = payload.extend_from_slice(&config.frequency_hz.to_le_bytes());
+                    let _ = payload.extend_from_slice(&config.duty_cycle_percent.to_le_bytes());
+                    let _ = payload.extend_from_slice(&config.waveform_factor.to_le_bytes());
+                    let _ = payload.extend_from_slice(&config.amplitude_percent.to_le_bytes());
+                    (CommandStatus::Ok, payload)
                 }
-            }
+                Some(UsbCommand::GetBatteryState) => {
+                    let state = ctx.shared.battery_state.lock(|state| *state);
+                    let mut payload = heapless::Vec::new();
+                    let _ = payload.push(state as u8);
+                    (CommandStatus::Ok, payload)
+                }
+                Some(UsbCommand::GetFirmwareVersion) => {
+                    let mut payload = heapless::Vec::new();
+                    let _ = payload.extend_from_slice(&crate::config::usb::DEVICE_RELEASE.to_le_bytes());
+                    (CommandStatus::Ok, payload)
+                }
+                Some(UsbCommand::GetSafetyStatus) => {
+                    let last_report = ctx.shared.last_safety_report.lock(|report| *report);
+                    let mut payload = heapless::Vec::new();
+                    match last_report {
+                        Some(report) => {
+                            let (primary, secondary) = report.error.measured_values();
+                            let _ = payload.push(1); // a trip has occurred
+                            let _ = payload.push(report.error.code());
+                            let _ = payload.push(report.error.severity_level());
+                            let _ = payload.extend_from_slice(&report.timestamp_ms.to_le_bytes());
+                            let _ = payload.extend_from_slice(&primary.to_le_bytes());
+                            let _ = payload.extend_from_slice(&secondary.to_le_bytes());
+                        }
+                        None => {
+                            let _ = payload.push(0); // no trip since boot
+                        }
+                    }
+                    (CommandStatus::Ok, payload)
+                }
+                Some(UsbCommand::SetFrequency(_)) | Some(UsbCommand::SetDutyCycle(_)) => {
+                    // Superseded by SetWaveform; acknowledged but not acted on
+                    (CommandStatus::Ok, heapless::Vec::new())
+                }
+                None => {
+                    // Unrecognized opcode - tell the host rather than
+                    // silently dropping its request
+                    #[cfg(feature = "usb-logs")]
+                    {
+                        use crate::types::logging::{LogCategory, LogLevel, LogMessage};
+
+                        let mut content = [0u8; 52];
+                        let msg_bytes = b"Unknown USB command opcode";
+                        let len = core::cmp::min(msg_bytes.len(), 52);
+                        content[..len].copy_from_slice(&msg_bytes[..len]);
+
+                        let allowed = ctx.shared.logging_config.lock(|config| {
+                            config.enabled && config.log_filter.allows(LogCategory::System, LogLevel::Warn)
+                        });
+                        if allowed {
+                            crate::drivers::logging::emit_defmt(&LogMessage {
+                                timestamp_ms: 0,
+                                category: LogCategory::System,
+                                level: LogLevel::Warn,
+                                content,
+                                content_len: len as u8,
+                            });
+                        }
+                    }
+
+                    (CommandStatus::UnknownOpcode, heapless::Vec::new())
+                }
+            };
+
+            let response = build_response_report(request.opcode, request.seq, status, &payload);
+            let _ = ctx
+                .shared
+                .hid_class
+                .lock(|hid_class| hid_class.push_raw_input(&response.data));
         }
 
         usb_command_handler_task::spawn_after(Duration::<u64, 1, 1000>::millis(10)).unwrap();
     }
 
+    /// Serial console task - drains queued log lines out the CDC-ACM
+    /// serial endpoint and parses newline-terminated text commands coming
+    /// back in, so a plain terminal on `/dev/ttyACM*` gets the same
+    /// stats/reset/soc affordances as the HID command channel without any
+    /// host-side tooling.
+    #[task(
+        shared = [serial, serial_log_queue, bootloader_state, battery_state, last_safety_report],
+        local = [serial_line],
+        priority = 1
+    )]
+    fn serial_console_task(mut ctx: serial_console_task::Context) {
+        use crate::drivers::serial_console::{format_log_line, parse_serial_command, SerialCommand};
+        use core::fmt::Write as _;
+
+        // Stream out whatever log lines queued up since the last tick.
+        // Best-effort: a full transmit buffer just leaves a line queued for
+        // the next tick rather than blocking the console on a stalled host.
+        ctx.shared.serial.lock(|serial| {
+            ctx.shared.serial_log_queue.lock(|queue| {
+                while let Some(message) = queue.peek() {
+                    let line = format_log_line(message);
+                    if serial.write(line.as_bytes()).is_err() {
+                        break;
+                    }
+                    let _ = queue.dequeue();
+                }
+            });
+        });
+
+        // Pull in whatever the host has typed since the last tick
+        let mut buffer = [0u8; 64];
+        let read = ctx
+            .shared
+            .serial
+            .lock(|serial| serial.read(&mut buffer).unwrap_or(0));
+
+        if read > 0 {
+            if let Some(line) = ctx.local.serial_line.feed(&buffer[..read]) {
+                let mut response: heapless::String<64> = heapless::String::new();
+                match parse_serial_command(&line) {
+                    Some(SerialCommand::Stats) => {
+                        let last_report = ctx.shared.last_safety_report.lock(|report| *report);
+                        let _ = match last_report {
+                            Some(report) => write!(
+                                response,
+                                "fw={} last_fault_code={}\r\n",
+                                crate::config::usb::DEVICE_RELEASE,
+                                report.error.code()
+                            ),
+                            None => write!(
+                                response,
+                                "fw={} last_fault=none\r\n",
+                                crate::config::usb::DEVICE_RELEASE
+                            ),
+                        };
+                    }
+                    Some(SerialCommand::Reset) => {
+                        let can_enter = ctx
+                            .shared
+                            .bootloader_state
+                            .lock(|state| matches!(*state, BootloaderState::Normal));
+                        if can_enter {
+                            let config = BootloaderConfig::default();
+                            bootloader_entry_task::spawn(config).ok();
+                            let _ = write!(response, "entering bootloader\r\n");
+                        } else {
+                            let _ = write!(response, "bootloader busy\r\n");
+                        }
+                    }
+                    Some(SerialCommand::Soc) => {
+                        let state = ctx.shared.battery_state.lock(|state| *state);
+                        let _ = write!(response, "battery_state={:?}\r\n", state);
+                    }
+                    None => {
+                        let _ = write!(response, "unknown command\r\n");
+                    }
+                }
+                ctx.shared.serial.lock(|serial| {
+                    let _ = serial.write(response.as_bytes());
+                });
+            }
+        }
+
+        serial_console_task::spawn_after(Duration::<u64, 1, 1000>::millis(20)).unwrap();
+    }
+
     /// Bootloader entry task - handles safe transition to ROM bootloader
     #[task(shared = [bootloader_state], priority = 2)]
     fn bootloader_entry_task(mut ctx: bootloader_entry_task::Context, config: BootloaderConfig) {
@@ -305,7 +585,7 @@ mod app {
     /// Logging transmission task - sends log messages via USB HID
     #[cfg(feature = "usb-logs")]
     #[task(
-        shared = [hid_class, log_queue, logging_config],
+        shared = [hid_class, log_queue, logging_config, usb_link_state, serial_log_queue],
         priority = 3
     )]
     fn logging_transmit_task(mut ctx: logging_transmit_task::Context) {
@@ -319,8 +599,24 @@ mod app {
             return;
         }
 
+        // Only drain the queue once the link is actually Configured; while
+        // Suspended/Detached there's no host to receive reports
+        let link_configured = ctx.shared.usb_link_state.lock(|state| state.is_configured());
+        if !link_configured {
+            logging_transmit_task::spawn_after(Duration::<u64, 1, 1000>::millis(100)).unwrap();
+            return;
+        }
+
         // Non-blocking queue operations to prevent task blocking
         if let Some(message) = dequeue_message() {
+            // Tee the same message to the serial console's queue so a
+            // terminal on the CDC-ACM port sees the same log stream as the
+            // HID logger, without the two transports contending over one
+            // consumer of `drivers::logging`'s queue
+            ctx.shared.serial_log_queue.lock(|queue| {
+                let _ = queue.enqueue(message);
+            });
+
             // Add debug log to see if we're processing messages
             #[cfg(feature = "system-logs")]
             {
@@ -389,13 +685,13 @@ mod app {
     /// CRITICAL: Uses Priority 4 to avoid conflicts with USB tasks (Priority 1)
     /// and logging tasks (Priority 3). Never change this priority.
     #[task(
-        local = [battery_pin],
-        shared = [battery_monitor, safety_flags, log_queue, logging_config, battery_state],
+        local = [battery_pin, battery_debug_log_limiter],
+        shared = [battery_monitor, safety_flags, log_queue, logging_config, battery_state, usb_link_state, safety_mailbox, last_safety_report],
         priority = 4
     )]
     fn battery_monitor_task(mut ctx: battery_monitor_task::Context) {
         use crate::types::errors::BatteryError;
-        
+
         // Process battery sample and handle all responses within proper locking
         let (battery_reading, requires_emergency_action) = (ctx.shared.battery_monitor, ctx.shared.safety_flags, ctx.shared.battery_state).lock(|monitor, flags, state| {
             // Process the battery sample
@@ -409,8 +705,10 @@ mod app {
                     } else {
                         None
                     };
-                    
-                    (Ok((reading, state_changed)), false)
+
+                    let sample_rejected = monitor.last_sample_rejected();
+
+                    (Ok((reading, state_changed, sample_rejected)), false)
                 },
                 Err(error) => {
                     // Determine if emergency response is needed
@@ -437,29 +735,85 @@ mod app {
         
         // Handle results and logging outside of the main lock
         match battery_reading {
-            Ok((reading, state_changed)) => {
+            Ok((reading, state_changed, sample_rejected)) => {
                 // Log battery information if usb-logs feature is enabled
                 #[cfg(feature = "usb-logs")]
                 {
-                    use crate::types::logging::{LogMessage, LogCategory, LogLevel};
-                    use heapless::spsc::Queue;
-                    
-                    let should_log = ctx.shared.logging_config.lock(|config| {
-                        config.enabled && config.enabled_categories & (1 << LogCategory::Battery as u8) != 0
+                    use crate::types::logging::{
+                        LogCategory, LogLevel, LogMessage, BATTERY_DEBUG_LOG_MAX_PER_WINDOW,
+                        BATTERY_DEBUG_LOG_WINDOW_MS,
+                    };
+
+                    // Surface a glitch-rejected ADC sample as its own
+                    // diagnostic entry over the same defmt/HID paths as the
+                    // ordinary reading below, rather than folding it
+                    // silently into the reading that replaced it
+                    if sample_rejected {
+                        let diagnostic_allowed = ctx.shared.logging_config.lock(|config| {
+                            config.enabled
+                                && config.log_filter.allows(LogCategory::Battery, LogLevel::Warn)
+                        });
+
+                        if diagnostic_allowed {
+                            let mut content = [0u8; 52];
+                            let msg_bytes = b"ADC sample rejected (jump exceeded limit)";
+                            let len = core::cmp::min(msg_bytes.len(), 52);
+                            content[..len].copy_from_slice(&msg_bytes[..len]);
+
+                            let diagnostic = LogMessage {
+                                timestamp_ms: reading.timestamp_ms,
+                                category: LogCategory::Battery,
+                                level: LogLevel::Warn,
+                                content,
+                                content_len: len as u8,
+                            };
+
+                            crate::drivers::logging::emit_defmt(&diagnostic);
+
+                            let usb_ready =
+                                ctx.shared.usb_link_state.lock(|state| state.is_configured());
+                            if usb_ready {
+                                ctx.shared.log_queue.lock(|queue| {
+                                    let _ = queue.enqueue_by_priority(diagnostic);
+                                });
+                            }
+                        }
+                    }
+
+                    let is_transition = state_changed.is_some();
+                    let level = if is_transition { LogLevel::Info } else { LogLevel::Debug };
+
+                    // The USB link being configured only matters for the HID
+                    // sink - the defmt-rtt sink (below) works over a probe
+                    // whether or not the host has enumerated the device yet,
+                    // which is exactly what makes it useful for boot-time logs
+                    let filter_allows = ctx.shared.logging_config.lock(|config| {
+                        config.enabled && config.log_filter.allows(LogCategory::Battery, level)
                     });
-                    
-                    if should_log {
-                        let log_message = if state_changed.is_some() {
+                    let usb_ready = ctx.shared.usb_link_state.lock(|state| state.is_configured());
+
+                    // State transitions always win; only the periodic Debug
+                    // reading is subject to the rate limiter
+                    let filter_allows = filter_allows
+                        && (is_transition
+                            || ctx.local.battery_debug_log_limiter.allow(
+                                reading.timestamp_ms,
+                                BATTERY_DEBUG_LOG_WINDOW_MS,
+                                BATTERY_DEBUG_LOG_MAX_PER_WINDOW,
+                            ));
+
+                    if filter_allows {
+                        let log_message = if is_transition {
                             // Log state transition
                             let mut content = [0u8; 52];
                             let msg_bytes = b"Battery state changed";
                             let len = core::cmp::min(msg_bytes.len(), 52);
                             content[..len].copy_from_slice(&msg_bytes[..len]);
-                            
+
                             LogMessage {
                                 timestamp_ms: reading.timestamp_ms,
                                 category: LogCategory::Battery,
-                                level: LogLevel::Info,
+                                level,
                                 content,
                                 content_len: len as u8,
                             }
@@ -469,50 +823,86 @@ mod app {
                             let msg_bytes = b"Battery reading";
                             let len = core::cmp::min(msg_bytes.len(), 52);
                             content[..len].copy_from_slice(&msg_bytes[..len]);
-                            
+
                             LogMessage {
                                 timestamp_ms: reading.timestamp_ms,
                                 category: LogCategory::Battery,
-                                level: LogLevel::Debug,
+                                level,
                                 content,
                                 content_len: len as u8,
                             }
                         };
-                        
-                        ctx.shared.log_queue.lock(|queue: &mut Queue<LogMessage, 32>| {
-                            let _ = queue.enqueue(log_message);
-                        });
+
+                        crate::drivers::logging::emit_defmt(&log_message);
+
+                        if usb_ready {
+                            ctx.shared.log_queue.lock(|queue| {
+                                let _ = queue.enqueue_by_priority(log_message);
+                            });
+                        }
                     }
                 }
             },
-            Err(_error) => {
+            Err(error) => {
+                // An emergency trip is published to the single-slot safety
+                // mailbox (and remembered in `last_safety_report`) regardless
+                // of whether usb-logs is enabled - the host needs this
+                // report even when ordinary logging is compiled out
+                if requires_emergency_action {
+                    let report = crate::types::battery::SafetyReport {
+                        timestamp_ms: crate::drivers::logging::get_timestamp_ms(),
+                        error,
+                    };
+
+                    (ctx.shared.safety_mailbox, ctx.shared.last_safety_report).lock(
+                        |mailbox, last_report| {
+                            // Single slot: a trip that hasn't been drained
+                            // yet is replaced by the newer one rather than
+                            // dropping the new report
+                            let _ = mailbox.dequeue();
+                            let _ = mailbox.enqueue(report);
+                            *last_report = Some(report);
+                        },
+                    );
+
+                    safety_notification_task::spawn().ok();
+                }
+
                 // Log the error if logging is available
                 #[cfg(feature = "usb-logs")]
                 {
-                    use crate::types::logging::{LogMessage, LogCategory, LogLevel};
-                    use heapless::spsc::Queue;
-                    
-                    let should_log = ctx.shared.logging_config.lock(|config| {
-                        config.enabled
+                    use crate::types::logging::{LogCategory, LogLevel, LogMessage};
+
+                    let level = if requires_emergency_action { LogLevel::Error } else { LogLevel::Warn };
+
+                    // See the `Ok` arm above: the defmt-rtt sink doesn't need
+                    // the USB link configured, the HID enqueue below does
+                    let filter_allows = ctx.shared.logging_config.lock(|config| {
+                        config.enabled && config.log_filter.allows(LogCategory::Battery, level)
                     });
-                    
-                    if should_log {
+                    let usb_ready = ctx.shared.usb_link_state.lock(|state| state.is_configured());
+
+                    if filter_allows {
                         let mut content = [0u8; 52];
                         let msg_bytes = b"Battery error detected";
                         let len = core::cmp::min(msg_bytes.len(), 52);
                         content[..len].copy_from_slice(&msg_bytes[..len]);
-                        
+
                         let log_message = LogMessage {
                             timestamp_ms: 0, // Would need actual timestamp from monotonic timer
                             category: LogCategory::Battery,
-                            level: if requires_emergency_action { LogLevel::Error } else { LogLevel::Warn },
+                            level,
                             content,
                             content_len: len as u8,
                         };
-                        
-                        ctx.shared.log_queue.lock(|queue: &mut Queue<LogMessage, 32>| {
-                            let _ = queue.enqueue(log_message);
-                        });
+
+                        crate::drivers::logging::emit_defmt(&log_message);
+
+                        if usb_ready {
+                            ctx.shared.log_queue.lock(|queue| {
+                                let _ = queue.enqueue_by_priority(log_message);
+                            });
+                        }
                     }
                 }
             }
@@ -522,4 +912,58 @@ mod app {
         // This maintains the required 10Hz battery monitoring frequency
         battery_monitor_task::spawn_after(Duration::<u64, 1, 1000>::millis(100)).unwrap();
     }
+
+    /// Safety notification task - drains the single-slot safety mailbox and
+    /// pushes an unsolicited HID report the moment `battery_monitor_task`
+    /// spawns it, ahead of any queued ordinary log traffic.
+    ///
+    /// CRITICAL: Priority 4, matching `battery_monitor_task`, so this report
+    /// can preempt `logging_transmit_task` (Priority 3) - a safety trip must
+    /// reach the host even while the log queue is mid-drain.
+    #[task(shared = [hid_class, safety_mailbox], priority = 4)]
+    fn safety_notification_task(mut ctx: safety_notification_task::Context) {
+        use crate::drivers::usb_command_handler::build_safety_notification_report;
+
+        let report = ctx.shared.safety_mailbox.lock(|mailbox| mailbox.dequeue());
+
+        if let Some(report) = report {
+            let out = build_safety_notification_report(&report);
+            let _ = ctx
+                .shared
+                .hid_class
+                .lock(|hid_class| hid_class.push_raw_input(&out.data));
+        }
+    }
+
+    /// USB audio streaming task - fills one isochronous packet per USB
+    /// frame with samples rendered from the current `waveform_config`.
+    ///
+    /// Runs every 1ms, matching the isochronous endpoint's full-speed
+    /// frame interval. Priority 2 sits between USB polling (1, must never
+    /// be starved) and logging transmission (3, can tolerate more jitter).
+    #[task(
+        local = [waveform_buffer],
+        shared = [waveform_config, audio_class, usb_link_state],
+        priority = 2
+    )]
+    fn audio_stream_task(mut ctx: audio_stream_task::Context) {
+        // Pick up any retuning from a SetWaveform command; update_config is
+        // a no-op unless the config actually changed
+        let config = ctx.shared.waveform_config.lock(|config| *config);
+        ctx.local.waveform_buffer.update_config(config);
+
+        // Keep rendering into the double buffer even before the link is
+        // configured, so the first packet after enumeration isn't silence
+        // left over from an empty buffer
+        ctx.shared.audio_class.lock(|audio_class| {
+            audio_class.refill(ctx.local.waveform_buffer);
+
+            let link_configured = ctx.shared.usb_link_state.lock(|state| state.is_configured());
+            if link_configured {
+                audio_class.write_frame();
+            }
+        });
+
+        audio_stream_task::spawn_after(Duration::<u64, 1, 1000>::millis(1)).unwrap();
+    }
 }
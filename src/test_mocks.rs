@@ -15,7 +15,7 @@ use core::cmp::{PartialEq, Eq, PartialOrd, Ord};
 use core::convert::From;
 use core::iter::Iterator;
 
-use crate::battery::BatteryState;
+use crate::battery::{classify_battery_health, BatteryState, ChargeMode};
 use crate::system_state::{
     SystemHealthData, TaskHealthStatus, MemoryUsageStats, ErrorCounters,
     HardwareStatusData, GpioStates, AdcReadings, UsbStatus
@@ -405,6 +405,9 @@ impl MockSystemState {
                 vref_voltage_mv: 3300,
                 adc_calibration_offset: 0,
                 adc_error_count: 0,
+                soc_percent: 0,
+                filtered_voltage_mv: 3300,
+                skip_count: 0,
             },
         }
     }
@@ -478,10 +481,22 @@ impl MockSystemState {
 
     /// Get system health data
     pub fn get_system_health(&self) -> SystemHealthData {
+        // No temperature sensor in the mock - assume room temperature so
+        // health classification reduces to the voltage checks tests exercise
+        let (battery_health, charge_band) = classify_battery_health(self.battery_voltage_mv, 250, 0);
+        let charge_mode = if self.battery_state == BatteryState::Charging {
+            ChargeMode::Normal
+        } else {
+            ChargeMode::None
+        };
+
         SystemHealthData {
             uptime_ms: self.uptime_ms,
             battery_state: self.battery_state.clone(),
             battery_voltage_mv: self.battery_voltage_mv,
+            battery_health,
+            charge_current_limit_ma: charge_band.max_charge_current_ma,
+            charge_mode,
             pemf_active: self.pemf_active,
             pemf_cycle_count: self.pemf_cycle_count,
             task_health_status: self.task_health.clone(),
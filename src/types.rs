@@ -4,6 +4,14 @@
 //! across the entire codebase, reducing type conversion errors and improving
 //! code clarity.
 
+pub mod battery;
+pub mod bootloader_types;
+pub mod errors;
+pub mod logging;
+pub mod usb_commands;
+pub mod usb_link;
+pub mod waveform;
+
 /// ADC reading value (0-4095 for 12-bit ADC)
 pub type AdcValue = u16;
 
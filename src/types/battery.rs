@@ -38,6 +38,357 @@ impl BatteryState {
     }
 }
 
+/// Hysteresis- and debounce-stabilized wrapper around
+/// `BatteryState::from_adc_reading`
+///
+/// A reading that hovers right at a threshold (e.g. 1425<->1426) would
+/// otherwise flap between states every sample, and each flap is a
+/// candidate trigger for downstream safety logic. `update` widens whichever
+/// threshold borders the currently committed state by
+/// `config::battery_state_tracker::HYSTERESIS_ADC` before reclassifying,
+/// then requires the resulting candidate to hold for
+/// `config::battery_state_tracker::DEBOUNCE_SAMPLES` consecutive calls
+/// before committing it, with the per-candidate counter reset whenever the
+/// candidate changes.
+pub struct BatteryStateTracker {
+    committed: BatteryState,
+    candidate: BatteryState,
+    candidate_count: u8,
+}
+
+impl BatteryStateTracker {
+    /// Create a tracker with no committed reading yet; starts at
+    /// `BatteryState::Normal` until the first `DEBOUNCE_SAMPLES` calls
+    /// settle on an actual state
+    pub fn new() -> Self {
+        Self {
+            committed: BatteryState::Normal,
+            candidate: BatteryState::Normal,
+            candidate_count: 0,
+        }
+    }
+
+    /// The currently committed state, unaffected until a new candidate
+    /// survives its debounce window
+    pub fn state(&self) -> BatteryState {
+        self.committed
+    }
+
+    /// Feed a new ADC sample through hysteresis and debounce, returning the
+    /// currently committed state
+    pub fn update(&mut self, adc_value: u16) -> BatteryState {
+        let candidate = Self::classify_with_hysteresis(adc_value, self.committed);
+
+        if candidate == self.candidate {
+            self.candidate_count = self.candidate_count.saturating_add(1);
+        } else {
+            self.candidate = candidate;
+            self.candidate_count = 1;
+        }
+
+        if self.candidate != self.committed
+            && self.candidate_count >= crate::config::battery_state_tracker::DEBOUNCE_SAMPLES
+        {
+            self.committed = self.candidate;
+        }
+
+        self.committed
+    }
+
+    /// Classify `adc_value`, widening whichever threshold borders `current`
+    /// by `HYSTERESIS_ADC` so jitter on that side doesn't produce a new
+    /// candidate; falls through to the plain threshold lookup otherwise
+    fn classify_with_hysteresis(adc_value: u16, current: BatteryState) -> BatteryState {
+        let margin = crate::config::battery_state_tracker::HYSTERESIS_ADC;
+
+        match current {
+            BatteryState::Low if adc_value <= LOW_BATTERY_ADC_THRESHOLD.saturating_add(margin) => {
+                BatteryState::Low
+            }
+            BatteryState::Normal
+                if adc_value > LOW_BATTERY_ADC_THRESHOLD.saturating_sub(margin)
+                    && adc_value < CHARGING_ADC_THRESHOLD.saturating_add(margin) =>
+            {
+                BatteryState::Normal
+            }
+            BatteryState::Charging
+                if adc_value >= CHARGING_ADC_THRESHOLD.saturating_sub(margin)
+                    && adc_value <= OVERVOLTAGE_ADC_THRESHOLD.saturating_add(margin) =>
+            {
+                BatteryState::Charging
+            }
+            BatteryState::Full if adc_value >= OVERVOLTAGE_ADC_THRESHOLD.saturating_sub(margin) => {
+                BatteryState::Full
+            }
+            BatteryState::Fault => BatteryState::Fault,
+            _ => BatteryState::from_adc_reading(adc_value),
+        }
+    }
+}
+
+impl Default for BatteryStateTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Battery health, a dimension distinct from `BatteryState`: `BatteryState`
+/// tracks charge level from voltage alone, while `BatteryHealth` folds in
+/// pack temperature and the state machine's own fault detection to answer
+/// whether it's safe to keep driving the waveform output at all.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatteryHealth {
+    /// Voltage and temperature both within safe operating range
+    Good = 0,
+    /// Pack temperature above `battery_temp::HIGH_TEMP_THRESHOLD_MDEG`
+    Overheat = 1,
+    /// Pack temperature below `battery_temp::LOW_TEMP_THRESHOLD_MDEG`
+    Cold = 2,
+    /// Voltage above `OVERVOLTAGE_ADC_THRESHOLD`
+    OverVoltage = 3,
+    /// Voltage below `UNDERVOLTAGE_ADC_THRESHOLD`
+    UnderVoltage = 4,
+    /// State machine already reported `BatteryState::Fault`
+    Dead = 5,
+}
+
+impl BatteryHealth {
+    /// Classify battery health from the voltage and temperature ADC
+    /// channels. A `state` already at `BatteryState::Fault` always reports
+    /// `Dead`, since the state machine has already flagged something the
+    /// voltage/temperature checks below wouldn't catch on their own;
+    /// otherwise temperature is checked before voltage, since a pack
+    /// outside its safe thermal range shouldn't be charged or discharged
+    /// regardless of its current voltage.
+    ///
+    /// Not yet called from firmware control flow: there is no battery
+    /// temperature ADC channel wired up in hardware today (no pin in
+    /// `config::pins`, no read of it anywhere in `main.rs`), so there is no
+    /// real `temp_adc` to pass it without fabricating a reading. Held back
+    /// pending that hardware channel rather than wired in against a made-up
+    /// value.
+    ///
+    /// TODO: this is library code only, not a delivered feature - wire it
+    /// into `battery_monitor_task` once a temperature ADC channel exists.
+    pub fn evaluate(voltage_adc: u16, temp_adc: u16, state: BatteryState) -> Self {
+        if state == BatteryState::Fault {
+            return BatteryHealth::Dead;
+        }
+
+        let temp_mdeg = adc_to_temp_mdeg(temp_adc);
+        if temp_mdeg > crate::config::battery_temp::HIGH_TEMP_THRESHOLD_MDEG {
+            return BatteryHealth::Overheat;
+        }
+        if temp_mdeg < crate::config::battery_temp::LOW_TEMP_THRESHOLD_MDEG {
+            return BatteryHealth::Cold;
+        }
+
+        if voltage_adc > OVERVOLTAGE_ADC_THRESHOLD {
+            return BatteryHealth::OverVoltage;
+        }
+        if voltage_adc < UNDERVOLTAGE_ADC_THRESHOLD {
+            return BatteryHealth::UnderVoltage;
+        }
+
+        BatteryHealth::Good
+    }
+}
+
+/// Convert a raw temperature-channel ADC reading to milli-degrees Celsius
+/// using the calibration slope/offset in `config::battery_temp`.
+pub fn adc_to_temp_mdeg(temp_adc: u16) -> i32 {
+    temp_adc as i32 * crate::config::battery_temp::TEMP_ADC_SLOPE_MDEG_PER_LSB
+        + crate::config::battery_temp::TEMP_ADC_OFFSET_MDEG
+}
+
+/// State-of-charge estimator combining an open-circuit-voltage lookup with
+/// coulomb counting. `soc_centipercent` tracks state of charge in hundredths
+/// of a percent (0-10_000) so small per-sample coulomb increments don't
+/// round away to nothing between updates; `soc_percent` truncates it to the
+/// 0-100 value callers actually want.
+///
+/// Not yet instantiated from firmware control flow: `update`'s coulomb
+/// counting needs a real `current_ma` reading, and there is no charge-current
+/// sensing channel in hardware today. Feeding it a fabricated 0 would always
+/// take the at-rest branch and silently disable the coulomb-counting half of
+/// the algorithm, so this is held back pending a real current-sense channel
+/// rather than wired in against a made-up value.
+///
+/// TODO: this is library code only, not a delivered feature - wire it into
+/// `battery_monitor_task` once a current-sense ADC channel exists.
+pub struct FuelGauge {
+    soc_centipercent: i32,
+    /// Set when booting into the "UV charging case"
+    /// (`check_uv_charging`) and cleared once the voltage recovers past
+    /// `config::fuel_gauge::UV_CHARGING_RECOVERY_VOLTAGE_MV`. While set,
+    /// `update` forces SoC to 0 and skips OCV re-anchoring, since the OCV
+    /// table would otherwise read a deeply depleted pack as having
+    /// meaningful charge just because a charger is holding its voltage up.
+    low_battery_boot: bool,
+}
+
+impl Default for FuelGauge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FuelGauge {
+    pub const fn new() -> Self {
+        Self {
+            soc_centipercent: 0,
+            low_battery_boot: false,
+        }
+    }
+
+    /// Initialize from a boot-time reading. If `check_uv_charging` flags
+    /// the boot condition, starts with SoC forced to 0 and
+    /// `low_battery_boot()` set; otherwise behaves like `reset_from_voltage`.
+    ///
+    /// Not yet called from firmware control flow, same as the rest of
+    /// `FuelGauge`: there is no charger-detect line in hardware today (no
+    /// pin in `config::pins`, nothing in `main.rs`), so `charger_present`
+    /// has no real value to pass at boot without fabricating one.
+    ///
+    /// TODO: this is library code only, not a delivered feature - wire it
+    /// into `init()` once a charger-detect signal exists.
+    pub fn init_from_boot(voltage_mv: u32, charger_present: bool) -> Self {
+        let mut gauge = Self::new();
+        if Self::check_uv_charging(voltage_mv, charger_present) {
+            gauge.low_battery_boot = true;
+            gauge.soc_centipercent = 0;
+        } else {
+            gauge.reset_from_voltage(voltage_mv);
+        }
+        gauge
+    }
+
+    /// True while the gauge is holding SoC at 0 pending voltage recovery
+    /// from a boot-time UV-charging condition
+    pub fn low_battery_boot(&self) -> bool {
+        self.low_battery_boot
+    }
+
+    /// A reading is the "UV charging case" when a charger is attached but
+    /// the cell voltage is still at or below
+    /// `config::fuel_gauge::UV_CHARGING_VOLTAGE_MV` - a deeply depleted
+    /// pack that happens to read a plausible voltage once the charger is
+    /// holding it up, rather than one that's genuinely recovered.
+    pub fn check_uv_charging(voltage_mv: u32, charger_present: bool) -> bool {
+        charger_present && voltage_mv <= crate::config::fuel_gauge::UV_CHARGING_VOLTAGE_MV
+    }
+
+    /// Current state-of-charge estimate, 0-100
+    pub fn soc_percent(&self) -> u8 {
+        (self.soc_centipercent / 100).clamp(0, 100) as u8
+    }
+
+    /// Re-anchor the estimate to the OCV table's reading for `voltage_mv`,
+    /// discarding any accumulated coulomb count. Called at boot, before any
+    /// charge-integration history exists, and by `update` whenever the pack
+    /// is at rest.
+    pub fn reset_from_voltage(&mut self, voltage_mv: u32) {
+        self.soc_centipercent = Self::ocv_soc_percent(voltage_mv) as i32 * 100;
+    }
+
+    /// Feed a fresh sample and return the updated state-of-charge, 0-100.
+    /// `current_ma` is signed (positive charging, negative discharging) and
+    /// `dt_ms` the elapsed time since the previous update. Integrates charge
+    /// via coulomb counting (`soc += current_ma * dt_ms / (3_600_000 *
+    /// capacity_mah) * 100`, where the 3_600_000 converts `dt_ms` from
+    /// milliseconds to hours, scaled up to centipercent precision) while the pack is under
+    /// load, and re-anchors to the OCV estimate whenever `current_ma`'s
+    /// magnitude drops below `config::fuel_gauge::REST_CURRENT_THRESHOLD_MA`
+    /// (battery at rest) to correct accumulated drift.
+    ///
+    /// While `low_battery_boot()` is set, this instead holds SoC at 0 and
+    /// ignores `current_ma`/`dt_ms` entirely until `voltage_mv` reaches
+    /// `config::fuel_gauge::UV_CHARGING_RECOVERY_VOLTAGE_MV`, at
+    /// which point it recalibrates from OCV and clears the flag.
+    pub fn update(&mut self, voltage_mv: u32, current_ma: i32, dt_ms: u32) -> u8 {
+        if self.low_battery_boot {
+            if voltage_mv >= crate::config::fuel_gauge::UV_CHARGING_RECOVERY_VOLTAGE_MV {
+                self.low_battery_boot = false;
+                self.reset_from_voltage(voltage_mv);
+            } else {
+                self.soc_centipercent = 0;
+            }
+            return self.soc_percent();
+        }
+
+        if current_ma.abs() < crate::config::fuel_gauge::REST_CURRENT_THRESHOLD_MA {
+            self.reset_from_voltage(voltage_mv);
+        } else {
+            let capacity_mah = crate::config::fuel_gauge::BATTERY_CAPACITY_MAH as i64;
+            let delta_centipercent =
+                (current_ma as i64 * dt_ms as i64 * 10_000) / (3_600_000 * capacity_mah);
+            self.soc_centipercent =
+                (self.soc_centipercent as i64 + delta_centipercent).clamp(0, 10_000) as i32;
+        }
+        self.soc_percent()
+    }
+
+    /// Linear interpolation over `config::fuel_gauge::OCV_TABLE`, clamping
+    /// to the first/last breakpoint's `soc_percent` outside its voltage range.
+    fn ocv_soc_percent(voltage_mv: u32) -> u8 {
+        let table = &crate::config::fuel_gauge::OCV_TABLE;
+        let first = &table[0];
+        if voltage_mv <= first.voltage_mv {
+            return first.soc_percent;
+        }
+        let last = &table[table.len() - 1];
+        if voltage_mv >= last.voltage_mv {
+            return last.soc_percent;
+        }
+
+        for pair in table.windows(2) {
+            let (lo, hi) = (&pair[0], &pair[1]);
+            if voltage_mv >= lo.voltage_mv && voltage_mv <= hi.voltage_mv {
+                let span_mv = hi.voltage_mv - lo.voltage_mv;
+                let span_percent = hi.soc_percent as u32 - lo.soc_percent as u32;
+                let offset_mv = voltage_mv - lo.voltage_mv;
+                return lo.soc_percent + ((offset_mv * span_percent) / span_mv) as u8;
+            }
+        }
+
+        last.soc_percent
+    }
+}
+
+/// Charging progress stage, distinct from `BatteryState` (voltage bucket)
+/// and `BatteryHealth` (safety classification): tracks where a charge cycle
+/// is within `drivers::battery_charge::ChargeController`'s termination
+/// algorithm.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargeState {
+    /// No charger attached
+    NotCharging = 0,
+    /// Charging toward the full-voltage threshold
+    Charging = 1,
+    /// Voltage and taper current both look like full charge; confirming
+    /// over `full_check_samples` consecutive updates before committing
+    FullCheck = 2,
+    /// Confirmed full charge; charge current suspended
+    Full = 3,
+    /// A `Full` cell's voltage sagged below the recharge threshold; running
+    /// another charge cycle
+    Recharge = 4,
+}
+
+/// Result of a `ChargeController::update` call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargeEvent {
+    /// No transition this call; still in the given `ChargeState`
+    Unchanged(ChargeState),
+    /// Committed a transition to the given `ChargeState`
+    Transitioned(ChargeState),
+    /// The charge cycle exceeded its configured maximum charge time without
+    /// reaching `ChargeState::Full`
+    Fault(crate::types::errors::BatteryError),
+}
+
 /// Safety flags for critical battery monitoring - thread-safe atomic operations
 #[derive(Debug)]
 pub struct SafetyFlags {
@@ -123,6 +474,18 @@ pub struct BatteryReading {
     pub safety_flags: u8,
 }
 
+/// Single-slot snapshot of the most recent safety trip. Published into a
+/// dedicated one-entry mailbox (not the 32-entry log queue) the instant
+/// `battery_monitor_task` detects an emergency, so a high-priority task can
+/// hand the host the specific fault and its measured value ahead of
+/// ordinary log traffic instead of it waiting behind the log queue's
+/// retries and rate limiting.
+#[derive(Clone, Copy, Debug)]
+pub struct SafetyReport {
+    pub timestamp_ms: u32,
+    pub error: crate::types::errors::BatteryError,
+}
+
 impl BatteryReading {
     /// Create new battery reading from ADC value and safety flags
     pub fn new(timestamp_ms: u32, adc_value: u16, safety_flags: &SafetyFlags) -> Self {
@@ -202,6 +565,168 @@ pub fn convert_voltage_mv_to_adc(voltage_mv: u16) -> u16 {
     
     // Convert to ADC reading
     let adc_value = (adc_voltage_mv * ADC_RESOLUTION as f32) / ADC_REFERENCE_VOLTAGE_MV as f32;
-    
+
     adc_value as u16
 }
+
+#[cfg(test)]
+mod uv_charging_tests {
+    use super::*;
+    use crate::config::fuel_gauge::{UV_CHARGING_RECOVERY_VOLTAGE_MV, UV_CHARGING_VOLTAGE_MV};
+
+    #[test]
+    fn check_uv_charging_requires_charger_present() {
+        assert!(!FuelGauge::check_uv_charging(UV_CHARGING_VOLTAGE_MV, false));
+        assert!(FuelGauge::check_uv_charging(UV_CHARGING_VOLTAGE_MV, true));
+    }
+
+    #[test]
+    fn check_uv_charging_only_below_threshold() {
+        assert!(FuelGauge::check_uv_charging(UV_CHARGING_VOLTAGE_MV, true));
+        assert!(!FuelGauge::check_uv_charging(UV_CHARGING_VOLTAGE_MV + 1, true));
+    }
+
+    #[test]
+    fn init_from_boot_in_uv_case_forces_soc_to_zero_and_sets_flag() {
+        let gauge = FuelGauge::init_from_boot(UV_CHARGING_VOLTAGE_MV, true);
+        assert!(gauge.low_battery_boot());
+        assert_eq!(gauge.soc_percent(), 0);
+    }
+
+    #[test]
+    fn init_from_boot_outside_uv_case_anchors_from_voltage_normally() {
+        let gauge = FuelGauge::init_from_boot(UV_CHARGING_RECOVERY_VOLTAGE_MV, true);
+        assert!(!gauge.low_battery_boot());
+    }
+
+    #[test]
+    fn update_holds_soc_at_zero_until_voltage_recovers() {
+        let mut gauge = FuelGauge::init_from_boot(UV_CHARGING_VOLTAGE_MV, true);
+        assert!(gauge.low_battery_boot());
+
+        // Still below the recovery threshold: stays flagged, SoC pinned to 0
+        let soc = gauge.update(UV_CHARGING_RECOVERY_VOLTAGE_MV - 1, 0, 1000);
+        assert_eq!(soc, 0);
+        assert!(gauge.low_battery_boot());
+
+        // Crosses the recovery threshold: flag clears and SoC re-anchors from OCV
+        let soc = gauge.update(UV_CHARGING_RECOVERY_VOLTAGE_MV, 0, 1000);
+        assert!(!gauge.low_battery_boot());
+        assert!(soc > 0);
+    }
+}
+
+#[cfg(test)]
+mod battery_health_tests {
+    use super::*;
+    use crate::config::battery_temp::{HIGH_TEMP_THRESHOLD_MDEG, LOW_TEMP_THRESHOLD_MDEG};
+
+    /// `temp_adc` that decodes (via `adc_to_temp_mdeg`) to room temperature,
+    /// safely inside the safe thermal band, so voltage-only cases below
+    /// don't also trip the temperature branches.
+    const ROOM_TEMP_ADC: u16 = 1000;
+
+    fn temp_adc_for_mdeg(target_mdeg: i32) -> u16 {
+        ((target_mdeg - crate::config::battery_temp::TEMP_ADC_OFFSET_MDEG)
+            / crate::config::battery_temp::TEMP_ADC_SLOPE_MDEG_PER_LSB) as u16
+    }
+
+    #[test]
+    fn evaluate_reports_dead_once_state_is_fault_regardless_of_readings() {
+        assert_eq!(
+            BatteryHealth::evaluate(0, ROOM_TEMP_ADC, BatteryState::Fault),
+            BatteryHealth::Dead
+        );
+    }
+
+    #[test]
+    fn evaluate_checks_temperature_before_voltage() {
+        let overheat_adc = temp_adc_for_mdeg(HIGH_TEMP_THRESHOLD_MDEG + 1_000);
+        assert_eq!(
+            BatteryHealth::evaluate(OVERVOLTAGE_ADC_THRESHOLD + 1, overheat_adc, BatteryState::Charging),
+            BatteryHealth::Overheat
+        );
+
+        let cold_adc = temp_adc_for_mdeg(LOW_TEMP_THRESHOLD_MDEG - 1_000);
+        assert_eq!(
+            BatteryHealth::evaluate(UNDERVOLTAGE_ADC_THRESHOLD - 1, cold_adc, BatteryState::Low),
+            BatteryHealth::Cold
+        );
+    }
+
+    #[test]
+    fn evaluate_flags_over_and_under_voltage_within_the_safe_thermal_band() {
+        assert_eq!(
+            BatteryHealth::evaluate(OVERVOLTAGE_ADC_THRESHOLD + 1, ROOM_TEMP_ADC, BatteryState::Full),
+            BatteryHealth::OverVoltage
+        );
+        assert_eq!(
+            BatteryHealth::evaluate(UNDERVOLTAGE_ADC_THRESHOLD - 1, ROOM_TEMP_ADC, BatteryState::Low),
+            BatteryHealth::UnderVoltage
+        );
+    }
+
+    #[test]
+    fn evaluate_reports_good_within_safe_voltage_and_temperature() {
+        assert_eq!(
+            BatteryHealth::evaluate(CHARGING_ADC_THRESHOLD, ROOM_TEMP_ADC, BatteryState::Charging),
+            BatteryHealth::Good
+        );
+    }
+}
+
+#[cfg(test)]
+mod fuel_gauge_tests {
+    use super::*;
+    use crate::config::fuel_gauge::{BATTERY_CAPACITY_MAH, REST_CURRENT_THRESHOLD_MA};
+
+    #[test]
+    fn reset_from_voltage_anchors_to_the_ocv_table_breakpoints() {
+        let mut gauge = FuelGauge::new();
+        gauge.reset_from_voltage(3_000);
+        assert_eq!(gauge.soc_percent(), 0);
+
+        gauge.reset_from_voltage(4_200);
+        assert_eq!(gauge.soc_percent(), 100);
+    }
+
+    #[test]
+    fn reset_from_voltage_interpolates_between_breakpoints() {
+        let mut gauge = FuelGauge::new();
+        // Midpoint of the 3600mV/25% - 3800mV/50% breakpoint pair
+        gauge.reset_from_voltage(3_700);
+        assert_eq!(gauge.soc_percent(), 37);
+    }
+
+    #[test]
+    fn update_re_anchors_from_ocv_while_the_pack_is_at_rest() {
+        let mut gauge = FuelGauge::new();
+        gauge.reset_from_voltage(3_300);
+        assert_eq!(gauge.soc_percent(), 10);
+
+        // |current_ma| below REST_CURRENT_THRESHOLD_MA: treated as at rest,
+        // so the estimate re-anchors to the OCV table instead of integrating
+        let soc = gauge.update(4_000, REST_CURRENT_THRESHOLD_MA - 1, 60_000);
+        assert_eq!(soc, 75);
+    }
+
+    #[test]
+    fn update_integrates_charge_current_via_coulomb_counting() {
+        let mut gauge = FuelGauge::new();
+        gauge.reset_from_voltage(3_800); // 50%
+
+        // Charging at exactly 1C (current_ma == pack capacity) integrates a
+        // full 100% of capacity per hour; over 0.3h that's +30%, landing at
+        // 80% without clamping.
+        let soc = gauge.update(3_800, BATTERY_CAPACITY_MAH as i32, 1_080_000);
+        assert_eq!(soc, 80);
+    }
+
+    #[test]
+    fn update_clamps_soc_to_0_100() {
+        let mut gauge = FuelGauge::new();
+        gauge.reset_from_voltage(4_200); // 100%
+        let soc = gauge.update(4_200, REST_CURRENT_THRESHOLD_MA + 100_000, 3_600_000);
+        assert_eq!(soc, 100);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::types::battery::BatteryState;
+use crate::types::battery::{BatteryState, ChargeState};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SystemError {
@@ -47,10 +47,17 @@ pub enum BatteryError {
     /// Battery temperature exceeded safe operating range
     /// Critical: Thermal runaway risk
     OverTemperature {
-        temperature_c: i16,
+        temp_mdeg: i32,
         current_state: BatteryState
     },
-    
+
+    /// Battery temperature below safe operating range
+    /// Critical: Charging/discharging a too-cold pack risks permanent damage
+    UnderTemperature {
+        temp_mdeg: i32,
+        current_state: BatteryState
+    },
+
     /// Charging circuit hardware malfunction detected
     /// Critical: Unsafe charging conditions
     ChargingCircuitFault {
@@ -65,6 +72,15 @@ pub enum BatteryError {
         to_state: BatteryState,
         trigger_adc: u16
     },
+
+    /// Charge cycle exceeded its configured maximum charge time without
+    /// reaching `ChargeState::Full`
+    /// Critical: Likely a stuck charger or failed taper detection - risk of
+    /// overcharge if charging isn't cut off
+    ChargeTimeout {
+        elapsed_ms: u32,
+        charge_state: ChargeState
+    },
 }
 
 impl BatteryError {
@@ -76,9 +92,11 @@ impl BatteryError {
             BatteryError::SafetyTimeout { .. } => true,
             BatteryError::OverCurrent { .. } => true,
             BatteryError::OverTemperature { .. } => true,
+            BatteryError::UnderTemperature { .. } => true,
             BatteryError::ChargingCircuitFault { .. } => true,
             BatteryError::AdcFailed => true, // Cannot monitor = unsafe
             BatteryError::InvalidStateTransition { .. } => false, // Log but continue
+            BatteryError::ChargeTimeout { .. } => true,
         }
     }
     
@@ -90,23 +108,143 @@ impl BatteryError {
             BatteryError::SafetyTimeout { .. } => 4, // Critical
             BatteryError::OverCurrent { .. } => 3,  // High
             BatteryError::OverTemperature { .. } => 4, // Critical
+            BatteryError::UnderTemperature { .. } => 4, // Critical
             BatteryError::ChargingCircuitFault { .. } => 3, // High
             BatteryError::AdcFailed => 4, // Critical
             BatteryError::InvalidStateTransition { .. } => 2, // Medium
+            BatteryError::ChargeTimeout { .. } => 3, // High
         }
     }
     
+    /// Stable per-variant discriminant for compact wire encoding (safety
+    /// notification reports, etc.) where the full enum can't be serialized
+    /// generically over a fixed-size HID report.
+    pub fn code(&self) -> u8 {
+        match self {
+            BatteryError::AdcFailed => 0,
+            BatteryError::OverVoltage { .. } => 1,
+            BatteryError::UnderVoltage { .. } => 2,
+            BatteryError::SafetyTimeout { .. } => 3,
+            BatteryError::OverCurrent { .. } => 4,
+            BatteryError::OverTemperature { .. } => 5,
+            BatteryError::ChargingCircuitFault { .. } => 6,
+            BatteryError::InvalidStateTransition { .. } => 7,
+            BatteryError::UnderTemperature { .. } => 8,
+            BatteryError::ChargeTimeout { .. } => 9,
+        }
+    }
+
+    /// The (primary, secondary) measured values carried by this variant,
+    /// for the same compact wire encoding `code()` serves. Zero for
+    /// whichever field doesn't apply to this variant.
+    pub fn measured_values(&self) -> (u16, u16) {
+        match self {
+            BatteryError::AdcFailed => (0, 0),
+            BatteryError::OverVoltage { adc_value, voltage_mv, .. } => (*adc_value, *voltage_mv),
+            BatteryError::UnderVoltage { adc_value, voltage_mv, .. } => (*adc_value, *voltage_mv),
+            BatteryError::SafetyTimeout { timeout_ms, .. } => (*timeout_ms as u16, 0),
+            BatteryError::OverCurrent { measured_current_ma, duration_ms } => {
+                (*measured_current_ma, *duration_ms as u16)
+            }
+            BatteryError::OverTemperature { temp_mdeg, .. } => (*temp_mdeg as u16, 0),
+            BatteryError::UnderTemperature { temp_mdeg, .. } => (*temp_mdeg as u16, 0),
+            BatteryError::ChargingCircuitFault { fault_code, .. } => (*fault_code as u16, 0),
+            BatteryError::InvalidStateTransition { trigger_adc, .. } => (*trigger_adc, 0),
+            BatteryError::ChargeTimeout { elapsed_ms, .. } => (*elapsed_ms as u16, 0),
+        }
+    }
+
     /// Get human-readable error description for logging
     pub fn description(&self) -> &'static str {
         match self {
             BatteryError::AdcFailed => "ADC failed to read battery voltage",
             BatteryError::OverVoltage { .. } => "Battery over-voltage detected",
-            BatteryError::UnderVoltage { .. } => "Battery under-voltage detected", 
+            BatteryError::UnderVoltage { .. } => "Battery under-voltage detected",
             BatteryError::SafetyTimeout { .. } => "Safety monitoring timeout",
             BatteryError::OverCurrent { .. } => "Charging over-current detected",
             BatteryError::OverTemperature { .. } => "Battery over-temperature",
+            BatteryError::UnderTemperature { .. } => "Battery under-temperature",
             BatteryError::ChargingCircuitFault { .. } => "Charging circuit fault",
             BatteryError::InvalidStateTransition { .. } => "Invalid battery state transition",
+            BatteryError::ChargeTimeout { .. } => "Charge timeout: full charge not reached within limit",
+        }
+    }
+}
+
+/// `defmt::Format` for `BatteryError`, implemented by hand rather than
+/// derived: `description()` already gives a stable human-readable label per
+/// variant, and `BatteryState` isn't itself `defmt::Format`, so the nested
+/// state fields are rendered as their raw ADC-mapped discriminant instead.
+#[cfg(feature = "defmt-logs")]
+impl defmt::Format for BatteryError {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            BatteryError::OverVoltage { adc_value, voltage_mv, current_state } => {
+                defmt::write!(
+                    fmt,
+                    "{=str} (adc={=u16}, mv={=u16}, state={=u8})",
+                    self.description(), adc_value, voltage_mv, *current_state as u8
+                );
+            }
+            BatteryError::UnderVoltage { adc_value, voltage_mv, current_state } => {
+                defmt::write!(
+                    fmt,
+                    "{=str} (adc={=u16}, mv={=u16}, state={=u8})",
+                    self.description(), adc_value, voltage_mv, *current_state as u8
+                );
+            }
+            BatteryError::SafetyTimeout { timeout_ms, last_known_state } => {
+                defmt::write!(
+                    fmt,
+                    "{=str} (timeout_ms={=u32}, state={=u8})",
+                    self.description(), timeout_ms, *last_known_state as u8
+                );
+            }
+            BatteryError::OverCurrent { measured_current_ma, duration_ms } => {
+                defmt::write!(
+                    fmt,
+                    "{=str} (ma={=u16}, duration_ms={=u32})",
+                    self.description(), measured_current_ma, duration_ms
+                );
+            }
+            BatteryError::OverTemperature { temp_mdeg, current_state } => {
+                defmt::write!(
+                    fmt,
+                    "{=str} (temp_mdeg={=i32}, state={=u8})",
+                    self.description(), temp_mdeg, *current_state as u8
+                );
+            }
+            BatteryError::UnderTemperature { temp_mdeg, current_state } => {
+                defmt::write!(
+                    fmt,
+                    "{=str} (temp_mdeg={=i32}, state={=u8})",
+                    self.description(), temp_mdeg, *current_state as u8
+                );
+            }
+            BatteryError::ChargingCircuitFault { fault_code, description } => {
+                defmt::write!(
+                    fmt,
+                    "{=str} (fault_code={=u8}, {=str})",
+                    self.description(), fault_code, description
+                );
+            }
+            BatteryError::InvalidStateTransition { from_state, to_state, trigger_adc } => {
+                defmt::write!(
+                    fmt,
+                    "{=str} ({=u8} -> {=u8}, adc={=u16})",
+                    self.description(), *from_state as u8, *to_state as u8, trigger_adc
+                );
+            }
+            BatteryError::AdcFailed => {
+                defmt::write!(fmt, "{=str}", self.description());
+            }
+            BatteryError::ChargeTimeout { elapsed_ms, charge_state } => {
+                defmt::write!(
+                    fmt,
+                    "{=str} (elapsed_ms={=u32}, state={=u8})",
+                    self.description(), elapsed_ms, *charge_state as u8
+                );
+            }
         }
     }
 }
@@ -2,6 +2,7 @@ use usbd_hid::descriptor::SerializedDescriptor;
 
 /// Log severity levels
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-logs", derive(defmt::Format))]
 #[repr(u8)]
 pub enum LogLevel {
     Debug = 0,
@@ -12,6 +13,7 @@ pub enum LogLevel {
 
 /// Log message categories
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-logs", derive(defmt::Format))]
 #[repr(u8)]
 pub enum LogCategory {
     Battery = 0,
@@ -30,14 +32,207 @@ pub struct LogMessage {
     pub content_len: u8,
 }
 
+impl LogMessage {
+    /// The message content as a `str`, trimmed to `content_len`. Invalid
+    /// UTF-8 (shouldn't happen since every producer writes ASCII) renders as
+    /// `"<invalid utf8>"` rather than panicking.
+    pub fn content_str(&self) -> &str {
+        let len = core::cmp::min(self.content_len as usize, self.content.len());
+        core::str::from_utf8(&self.content[..len]).unwrap_or("<invalid utf8>")
+    }
+}
+
+/// `defmt::Format` for `LogMessage`, implemented by hand rather than derived
+/// since `content`/`content_len` should render as the trimmed message text,
+/// not as a raw byte array.
+#[cfg(feature = "defmt-logs")]
+impl defmt::Format for LogMessage {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "[{=u32}] {} {}: {=str}",
+            self.timestamp_ms,
+            self.level,
+            self.category,
+            self.content_str()
+        );
+    }
+}
+
 /// Configuration structure for runtime control
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct LoggingConfig {
     pub enabled_categories: u8, // Bitmask for enabled categories
     pub verbosity_level: LogLevel,
     pub enabled: bool,
+    pub log_filter: LogFilter,
+}
+
+/// Per-category log filtering: an allow mask plus a minimum severity per
+/// category, on top of the crate-wide `enabled_categories`/`verbosity_level`.
+/// Modeled on Android logd's `LogWhiteBlackList`, so a single noisy category
+/// can be tuned (or silenced) independently at runtime without touching the
+/// other categories' verbosity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LogFilter {
+    pub category_allow_mask: u8,
+    pub category_min_level: [LogLevel; 4],
+}
+
+impl LogFilter {
+    /// Every category allowed at every severity, i.e. no additional
+    /// filtering beyond `LoggingConfig`'s own fields
+    pub const fn allow_all() -> Self {
+        Self {
+            category_allow_mask: 0xF,
+            category_min_level: [LogLevel::Debug; 4],
+        }
+    }
+
+    /// Whether a message of `level` in `category` passes this filter
+    pub fn allows(&self, category: LogCategory, level: LogLevel) -> bool {
+        let bit = 1 << (category as u8);
+        self.category_allow_mask & bit != 0
+            && level as u8 >= self.category_min_level[category as usize] as u8
+    }
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self::allow_all()
+    }
+}
+
+/// Fixed-capacity log queue that prunes by priority instead of FIFO once
+/// full: a full queue evicts its lowest-severity entry to make room for an
+/// incoming message that outranks it, so `Error`/emergency messages are
+/// never lost to `Debug` spam. Intended for RTIC `Shared` resources, where
+/// the resource's lock already serializes access, so no atomics are needed
+/// here (contrast `drivers::logging`'s `LOG_QUEUE`, a `heapless::spsc::Queue`
+/// reached from outside any lock, which relies on that queue's own atomics).
+#[derive(Clone, Copy, Debug)]
+pub struct PriorityLogQueue<const N: usize> {
+    entries: [Option<LogMessage>; N],
+    len: usize,
+}
+
+impl<const N: usize> PriorityLogQueue<N> {
+    pub const fn new() -> Self {
+        Self {
+            entries: [None; N],
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Enqueue `msg`. If the queue is full, the lowest-severity entry
+    /// already queued is evicted as long as `msg` outranks it; otherwise
+    /// `msg` itself is dropped. Returns whether `msg` was queued.
+    pub fn enqueue_by_priority(&mut self, msg: LogMessage) -> bool {
+        if !self.is_full() {
+            self.entries[self.len] = Some(msg);
+            self.len += 1;
+            return true;
+        }
+
+        let lowest = self
+            .entries
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, entry)| entry.as_ref().map(|m| m.level as u8).unwrap_or(u8::MAX));
+
+        if let Some((index, Some(lowest_msg))) = lowest {
+            if msg.level as u8 > lowest_msg.level as u8 {
+                self.entries[index] = Some(msg);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Dequeue the oldest remaining message. Eviction can leave a message in
+    /// an earlier slot than its arrival order would suggest, so "oldest" here
+    /// means oldest surviving slot position, not strict arrival time.
+    pub fn dequeue(&mut self) -> Option<LogMessage> {
+        for i in 0..N {
+            if let Some(msg) = self.entries[i].take() {
+                self.entries.copy_within(i + 1..N, i);
+                self.entries[N - 1] = None;
+                self.len -= 1;
+                return Some(msg);
+            }
+        }
+        None
+    }
+}
+
+impl<const N: usize> Default for PriorityLogQueue<N> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
+/// Trailing-window rate limiter for periodic low-priority log sources (e.g.
+/// battery `Debug` readings), so a steady stream of routine samples can't
+/// crowd a [`PriorityLogQueue`] ahead of the rare messages that matter, even
+/// before priority-based eviction comes into play.
+#[derive(Clone, Copy, Debug)]
+pub struct LogRateLimiter {
+    window_start_ms: u32,
+    count_in_window: u8,
+}
+
+impl LogRateLimiter {
+    pub const fn new() -> Self {
+        Self {
+            window_start_ms: 0,
+            count_in_window: 0,
+        }
+    }
+
+    /// Record one attempt at `now_ms`; returns `true` if it falls within
+    /// `max_per_window` for the current `window_ms`-long trailing window,
+    /// `false` if it should be dropped instead.
+    pub fn allow(&mut self, now_ms: u32, window_ms: u32, max_per_window: u8) -> bool {
+        if now_ms.saturating_sub(self.window_start_ms) >= window_ms {
+            self.window_start_ms = now_ms;
+            self.count_in_window = 0;
+        }
+
+        if self.count_in_window >= max_per_window {
+            false
+        } else {
+            self.count_in_window += 1;
+            true
+        }
+    }
+}
+
+impl Default for LogRateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rate-limit window for periodic battery `Debug` readings
+pub const BATTERY_DEBUG_LOG_WINDOW_MS: u32 = 1000;
+/// Maximum periodic battery `Debug` readings allowed per
+/// [`BATTERY_DEBUG_LOG_WINDOW_MS`]-long window before they start getting
+/// dropped in favor of state transitions, which are never rate-limited
+pub const BATTERY_DEBUG_LOG_MAX_PER_WINDOW: u8 = 5;
+
 /// HID Report structure for log messages
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
@@ -1,10 +1,40 @@
+use crate::types::logging::LogFilter;
+use crate::types::waveform::WaveformConfig;
 use usbd_hid::descriptor::SerializedDescriptor;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+// Note: derives PartialEq but not Eq, since SetWaveform carries a
+// WaveformConfig whose f32 fields aren't Eq
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum UsbCommand {
     SetFrequency(u32),
     SetDutyCycle(u8),
     EnterBootloader,
+    SetLogFilter(LogFilter),
+    SetWaveform(WaveformConfig),
+    GetBatteryState,
+    GetWaveform,
+    GetFirmwareVersion,
+    GetSafetyStatus,
+}
+
+/// A parsed host request: the decoded `command` (`None` for an opcode this
+/// firmware doesn't recognize) plus the `opcode`/`seq` the host sent, both
+/// echoed back in the correlated [`CommandStatus`] response report so the
+/// host can match a reply to the request that triggered it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CommandRequest {
+    pub opcode: u8,
+    pub seq: u8,
+    pub command: Option<UsbCommand>,
+}
+
+/// Result status carried in a command response report
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommandStatus {
+    Ok = 0,
+    Error = 1,
+    UnknownOpcode = 2,
 }
 
 /// Minimal HID Report structure for USB enumeration
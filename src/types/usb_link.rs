@@ -0,0 +1,107 @@
+//! USB link-state machine
+//!
+//! `usb_poll_task` used to call `usb_dev.poll()` blindly every tick with no
+//! notion of whether the host had actually attached, enumerated, or gone to
+//! sleep, so downstream tasks (log transmission, the boot announcement)
+//! could only guess from a timer instead of reacting to real enumeration.
+//! This tracks `usb_dev.state()` as a small staged state machine -
+//! `Detached` / `Attached` / `Configured` / `Suspended`, modeled on the
+//! SAMD21 host driver's staging - and reports transitions as events on the
+//! `usb_link_events` queue; downstream tasks read the committed state
+//! through `UsbLinkState::is_configured()`.
+
+use usb_device::device::UsbDeviceState;
+
+/// Poll ticks the bus must stay in `Configured` before `Attached` is
+/// promoted to `Configured`, to ride out bus glitches and renumeration
+pub const CONFIGURED_SETTLE_TICKS: u16 = 200;
+
+/// Staged USB link state, advanced once per `usb_poll_task` tick from the
+/// bus's raw `UsbDeviceState`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UsbLinkState {
+    Detached,
+    Attached { settle_ticks: u16 },
+    Configured,
+    Suspended,
+}
+
+impl Default for UsbLinkState {
+    fn default() -> Self {
+        UsbLinkState::Detached
+    }
+}
+
+impl UsbLinkState {
+    pub const fn new() -> Self {
+        UsbLinkState::Detached
+    }
+
+    /// Advance by one poll tick given the bus's current raw state, returning
+    /// the new state and the event to emit, if this tick crossed a boundary
+    /// downstream tasks care about
+    pub fn advance(self, bus_state: UsbDeviceState) -> (Self, Option<UsbLinkEvent>) {
+        match (self, bus_state) {
+            (UsbLinkState::Detached, UsbDeviceState::Suspend) => (self, None),
+            (UsbLinkState::Detached, _) => (
+                UsbLinkState::Attached {
+                    settle_ticks: CONFIGURED_SETTLE_TICKS,
+                },
+                Some(UsbLinkEvent::Attached),
+            ),
+
+            (UsbLinkState::Attached { settle_ticks }, UsbDeviceState::Configured) => {
+                if settle_ticks <= 1 {
+                    (UsbLinkState::Configured, Some(UsbLinkEvent::Configured))
+                } else {
+                    (
+                        UsbLinkState::Attached {
+                            settle_ticks: settle_ticks - 1,
+                        },
+                        None,
+                    )
+                }
+            }
+            (UsbLinkState::Attached { .. }, UsbDeviceState::Suspend) => {
+                (UsbLinkState::Detached, Some(UsbLinkEvent::Detached))
+            }
+            (UsbLinkState::Attached { .. }, _) => (
+                // Not yet configured this tick; restart the settle countdown
+                // rather than trust a bus glitch mid-enumeration
+                UsbLinkState::Attached {
+                    settle_ticks: CONFIGURED_SETTLE_TICKS,
+                },
+                None,
+            ),
+
+            (UsbLinkState::Configured, UsbDeviceState::Configured) => (self, None),
+            (UsbLinkState::Configured, UsbDeviceState::Suspend) => {
+                (UsbLinkState::Suspended, Some(UsbLinkEvent::Suspended))
+            }
+            (UsbLinkState::Configured, _) => {
+                (UsbLinkState::Detached, Some(UsbLinkEvent::Detached))
+            }
+
+            (UsbLinkState::Suspended, UsbDeviceState::Configured) => {
+                (UsbLinkState::Configured, Some(UsbLinkEvent::Configured))
+            }
+            (UsbLinkState::Suspended, UsbDeviceState::Suspend) => (self, None),
+            (UsbLinkState::Suspended, _) => (UsbLinkState::Detached, Some(UsbLinkEvent::Detached)),
+        }
+    }
+
+    /// Whether the link is settled enough for downstream tasks (log
+    /// transmission, battery-log enqueue) to act on it
+    pub fn is_configured(&self) -> bool {
+        matches!(self, UsbLinkState::Configured)
+    }
+}
+
+/// Link transition reported to downstream tasks via the event queue
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UsbLinkEvent {
+    Attached,
+    Configured,
+    Suspended,
+    Detached,
+}
@@ -1,3 +1,5 @@
+use heapless::Vec;
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct WaveformConfig {
     pub frequency_hz: f32,        // 0.1 to 100Hz
@@ -23,16 +25,38 @@ pub struct WaveformSample {
     pub timestamp_us: u32,
 }
 
-pub const SAMPLE_BUFFER_SIZE: usize = 1000;
 pub const SAMPLE_RATE_HZ: u32 = 10000;
 pub const PWM_RESOLUTION_BITS: u8 = 12;
 pub const PWM_MAX_VALUE: u16 = (1 << PWM_RESOLUTION_BITS) - 1;
 
-/// Circular buffer for waveform samples with efficient generation
+/// Size of the DDS lookup table holding one normalized cycle of the
+/// configured waveform. Fixed and independent of frequency, unlike the old
+/// samples-per-cycle buffer it replaces.
+pub const WAVEFORM_TABLE_SIZE: usize = 1024;
+const WAVEFORM_TABLE_SIZE_LOG2: u32 = 10; // log2(WAVEFORM_TABLE_SIZE)
+
+/// Width, in bits, of a `u32` phase accumulator
+const PHASE_BITS: u32 = 32;
+/// `2^32`, the phase accumulator's full range, used to convert between
+/// `phase_increment` and a frequency in Hz
+const PHASE_ACCUMULATOR_RANGE: f64 = 4_294_967_296.0;
+
+/// Numerically-controlled oscillator (DDS) waveform generator.
+///
+/// One normalized cycle of the configured waveform is precomputed into a
+/// fixed-size lookup table, and a 32-bit phase accumulator walks through it:
+/// `phase` advances by `phase_increment` every sample, and the table index
+/// is the top `WAVEFORM_TABLE_SIZE_LOG2` bits of `phase`. Because
+/// `phase_increment = frequency_hz * 2^32 / SAMPLE_RATE_HZ`, frequency
+/// resolution is ~0.0000023Hz and memory use is constant across the full
+/// 0.1Hz-100Hz range (the old "samples-per-cycle copy" scheme needed
+/// 100,000 samples to represent 0.1Hz and clamped/truncated everything
+/// below that, corrupting the effective frequency). Wraparound is free via
+/// `u32` overflow.
 pub struct WaveformBuffer {
-    samples: [u16; SAMPLE_BUFFER_SIZE],
-    current_index: usize,
-    samples_per_cycle: usize,
+    table: [u16; WAVEFORM_TABLE_SIZE],
+    phase: u32,
+    phase_increment: u32,
     config: WaveformConfig,
     buffer_valid: bool,
 }
@@ -41,15 +65,15 @@ impl WaveformBuffer {
     /// Create new waveform buffer with default configuration
     pub fn new() -> Self {
         Self {
-            samples: [0; SAMPLE_BUFFER_SIZE],
-            current_index: 0,
-            samples_per_cycle: SAMPLE_BUFFER_SIZE,
+            table: [0; WAVEFORM_TABLE_SIZE],
+            phase: 0,
+            phase_increment: 0,
             config: WaveformConfig::default(),
             buffer_valid: false,
         }
     }
 
-    /// Update configuration and regenerate samples if needed
+    /// Update configuration and regenerate the lookup table if needed
     pub fn update_config(&mut self, new_config: WaveformConfig) -> bool {
         if self.config != new_config {
             self.config = new_config;
@@ -60,50 +84,52 @@ impl WaveformBuffer {
         }
     }
 
-    /// Regenerate all samples based on current configuration
+    /// Regenerate the lookup table and phase increment based on the current
+    /// configuration
     pub fn regenerate_samples(&mut self) {
         use crate::utils::waveforms::{generate_waveform_value, waveform_to_pwm};
 
-        // Calculate samples per cycle based on frequency
-        let period_samples = (SAMPLE_RATE_HZ as f32 / self.config.frequency_hz) as usize;
-        self.samples_per_cycle = period_samples.min(SAMPLE_BUFFER_SIZE);
+        let duty_cycle_normalized = self.config.duty_cycle_percent / 100.0;
+
+        for (i, entry) in self.table.iter_mut().enumerate() {
+            let time_in_cycle = i as f32 / WAVEFORM_TABLE_SIZE as f32;
 
-        // Generate samples for one complete cycle
-        for i in 0..self.samples_per_cycle {
-            let time_in_cycle = i as f32 / self.samples_per_cycle as f32;
-            let duty_cycle_normalized = self.config.duty_cycle_percent / 100.0;
-            
             let waveform_value = generate_waveform_value(
                 time_in_cycle,
                 self.config.waveform_factor,
                 duty_cycle_normalized,
             );
-            
-            self.samples[i] = waveform_to_pwm(waveform_value, self.config.amplitude_percent);
-        }
 
-        // Fill remaining buffer with copies of the cycle for seamless looping
-        for i in self.samples_per_cycle..SAMPLE_BUFFER_SIZE {
-            let cycle_index = i % self.samples_per_cycle;
-            self.samples[i] = self.samples[cycle_index];
+            *entry = waveform_to_pwm(waveform_value, self.config.amplitude_percent);
         }
 
+        self.phase_increment = (self.config.frequency_hz as f64 * PHASE_ACCUMULATOR_RANGE
+            / SAMPLE_RATE_HZ as f64) as u32;
         self.buffer_valid = true;
-        self.current_index = 0;
+        self.phase = 0;
     }
 
-    /// Get next PWM value from buffer (used by interrupt handler)
+    /// Get next PWM value from the lookup table (used by interrupt handler),
+    /// linearly interpolating between adjacent table entries using the
+    /// phase bits below the table index to cut quantization noise
     pub fn get_next_sample(&mut self) -> u16 {
         if !self.buffer_valid {
             return 0;
         }
 
-        let sample = self.samples[self.current_index];
-        
-        // Advance to next sample with wraparound
-        self.current_index = (self.current_index + 1) % SAMPLE_BUFFER_SIZE;
-        
-        sample
+        let frac_bits_width = PHASE_BITS - WAVEFORM_TABLE_SIZE_LOG2;
+        let idx = (self.phase >> frac_bits_width) as usize;
+        let next_idx = (idx + 1) % WAVEFORM_TABLE_SIZE;
+        let frac = (self.phase & ((1u32 << frac_bits_width) - 1)) as f32
+            / (1u32 << frac_bits_width) as f32;
+
+        let sample_a = self.table[idx] as f32;
+        let sample_b = self.table[next_idx] as f32;
+        let sample = sample_a + (sample_b - sample_a) * frac;
+
+        self.phase = self.phase.wrapping_add(self.phase_increment);
+
+        sample as u16
     }
 
     /// Get current configuration
@@ -116,22 +142,20 @@ impl WaveformBuffer {
         self.buffer_valid
     }
 
-    /// Reset buffer position to start of cycle
+    /// Reset phase accumulator to the start of the cycle
     pub fn reset_position(&mut self) {
-        self.current_index = 0;
+        self.phase = 0;
     }
 
     /// Get buffer utilization for diagnostics
     pub fn get_diagnostics(&self) -> WaveformBufferDiagnostics {
+        let frac_bits_width = PHASE_BITS - WAVEFORM_TABLE_SIZE_LOG2;
         WaveformBufferDiagnostics {
-            samples_per_cycle: self.samples_per_cycle,
-            current_index: self.current_index,
+            samples_per_cycle: WAVEFORM_TABLE_SIZE,
+            current_index: (self.phase >> frac_bits_width) as usize,
             buffer_valid: self.buffer_valid,
-            effective_frequency: if self.samples_per_cycle > 0 {
-                SAMPLE_RATE_HZ as f32 / self.samples_per_cycle as f32
-            } else {
-                0.0
-            },
+            effective_frequency: (self.phase_increment as f64 * SAMPLE_RATE_HZ as f64
+                / PHASE_ACCUMULATOR_RANGE) as f32,
         }
     }
 }
@@ -144,8 +168,192 @@ impl Default for WaveformBuffer {
 
 #[derive(Debug, Clone, Copy)]
 pub struct WaveformBufferDiagnostics {
+    /// Size of the DDS lookup table (fixed, independent of frequency)
     pub samples_per_cycle: usize,
+    /// Current lookup-table index, derived from the phase accumulator
     pub current_index: usize,
     pub buffer_valid: bool,
     pub effective_frequency: f32,
 }
+
+/// Maximum number of segments held by a single `WaveformSequence`
+pub const MAX_SEQUENCE_SEGMENTS: usize = 16;
+
+/// One step of a `WaveformSequence`: a waveform configuration held for
+/// `duration_ms`, optionally ramping in from the previous segment's values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SequenceSegment {
+    pub config: WaveformConfig,
+    pub duration_ms: u32,
+    /// When true, `frequency_hz` and `amplitude_percent` linearly
+    /// interpolate from the previous segment's ending values to this
+    /// segment's `config` over `duration_ms`, instead of stepping
+    /// immediately at the boundary — used for fade-in/fade-out programs.
+    pub ramp: bool,
+}
+
+/// Errors rejecting a segment from a `WaveformSequence`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SequenceError {
+    /// The segment's `WaveformConfig` failed `validate_config`
+    InvalidConfig,
+    /// The sequence already holds `MAX_SEQUENCE_SEGMENTS` segments
+    SequenceFull,
+}
+
+/// A playlist of `SequenceSegment`s played back in order and looped
+/// `repeat_count` times (0 = loop forever), mirroring the sequence-playback
+/// model used by PWM sequencers elsewhere (e.g. embassy's `SequencePwm`).
+/// Every segment is validated through `validate_config` before it is
+/// accepted, so a sequence can never be built around an out-of-range
+/// `WaveformConfig`.
+#[derive(Clone)]
+pub struct WaveformSequence {
+    segments: Vec<SequenceSegment, MAX_SEQUENCE_SEGMENTS>,
+    repeat_count: u32,
+}
+
+impl WaveformSequence {
+    /// Create an empty sequence. `repeat_count` of 0 loops forever.
+    pub fn new(repeat_count: u32) -> Self {
+        Self {
+            segments: Vec::new(),
+            repeat_count,
+        }
+    }
+
+    /// Validate and append a segment to the end of the playlist
+    pub fn push_segment(&mut self, segment: SequenceSegment) -> Result<(), SequenceError> {
+        if !crate::config::validation::validate_config(&segment.config) {
+            return Err(SequenceError::InvalidConfig);
+        }
+        self.segments
+            .push(segment)
+            .map_err(|_| SequenceError::SequenceFull)
+    }
+
+    pub fn segment(&self, index: usize) -> Option<&SequenceSegment> {
+        self.segments.get(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    pub fn repeat_count(&self) -> u32 {
+        self.repeat_count
+    }
+}
+
+/// Linear interpolation used by `SequencePlayer` to ramp between segments
+fn lerp(start: f32, end: f32, t: f32) -> f32 {
+    start + (end - start) * t
+}
+
+/// Drives playback of a `WaveformSequence`: advances segments on a
+/// millisecond tick (via `tick`) and pushes the resulting `WaveformConfig`
+/// into a `WaveformBuffer` with `update_config`, ramping `frequency_hz` and
+/// `amplitude_percent` across a segment's duration when that segment has
+/// `ramp` set.
+pub struct SequencePlayer {
+    segment_index: usize,
+    elapsed_in_segment_ms: u32,
+    loops_completed: u32,
+    /// The values a ramping segment interpolates from: the previous
+    /// segment's ending config, or the first segment's own config if
+    /// nothing has played yet.
+    ramp_start: WaveformConfig,
+    started: bool,
+    finished: bool,
+}
+
+impl SequencePlayer {
+    pub fn new() -> Self {
+        Self {
+            segment_index: 0,
+            elapsed_in_segment_ms: 0,
+            loops_completed: 0,
+            ramp_start: WaveformConfig::default(),
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// True once a finite (`repeat_count != 0`) sequence has played through
+    /// its last repeat; a `repeat_count` of 0 never finishes.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Advance playback by `delta_ms`, writing the current segment's config
+    /// (or, mid-ramp, the interpolated config) into `buffer`. No-op once
+    /// `is_finished()` or if `sequence` has no segments.
+    pub fn tick(&mut self, sequence: &WaveformSequence, buffer: &mut WaveformBuffer, delta_ms: u32) {
+        if self.finished || sequence.is_empty() {
+            return;
+        }
+
+        if !self.started {
+            self.started = true;
+            let first = sequence.segment(0).expect("sequence is non-empty");
+            self.ramp_start = first.config;
+            buffer.update_config(first.config);
+        }
+
+        self.elapsed_in_segment_ms = self.elapsed_in_segment_ms.saturating_add(delta_ms);
+
+        let segment = sequence
+            .segment(self.segment_index)
+            .expect("segment_index kept within bounds");
+
+        if segment.ramp && segment.duration_ms > 0 {
+            let t = core::cmp::min(self.elapsed_in_segment_ms, segment.duration_ms) as f32
+                / segment.duration_ms as f32;
+            buffer.update_config(WaveformConfig {
+                frequency_hz: lerp(self.ramp_start.frequency_hz, segment.config.frequency_hz, t),
+                duty_cycle_percent: segment.config.duty_cycle_percent,
+                waveform_factor: segment.config.waveform_factor,
+                amplitude_percent: lerp(
+                    self.ramp_start.amplitude_percent,
+                    segment.config.amplitude_percent,
+                    t,
+                ),
+            });
+        }
+
+        if self.elapsed_in_segment_ms < segment.duration_ms {
+            return;
+        }
+
+        self.elapsed_in_segment_ms = 0;
+        self.ramp_start = segment.config;
+        self.segment_index += 1;
+
+        if self.segment_index >= sequence.len() {
+            self.segment_index = 0;
+            self.loops_completed = self.loops_completed.saturating_add(1);
+
+            if sequence.repeat_count() != 0 && self.loops_completed >= sequence.repeat_count() {
+                self.finished = true;
+                return;
+            }
+        }
+
+        let next = sequence
+            .segment(self.segment_index)
+            .expect("segment_index kept within bounds");
+        if !next.ramp {
+            buffer.update_config(next.config);
+        }
+    }
+}
+
+impl Default for SequencePlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,181 @@
+use heapless::HistoryBuffer;
+
+/// Ring-buffer moving-average filter for raw battery ADC samples, with
+/// bounded-jump glitch rejection on top
+///
+/// `BatteryState::from_adc_reading` and `BatteryStateTracker` both classify
+/// a single raw sample, which is noisy enough on its own to cause spurious
+/// state churn. `VoltageFilter::update` feeds each raw sample into a
+/// fixed-size ring buffer and returns the moving average over the last
+/// `config::voltage_filter::WINDOW_SAMPLES` accepted samples, while
+/// `instantaneous()` keeps the latest raw reading available separately -
+/// the `batt_vol_adc` vs `batt_vol_aver` split seen in hardened battery
+/// drivers.
+///
+/// A sample that deviates from the current average by more than
+/// `config::voltage_filter::SPIKE_REJECT_DELTA_ADC` (`MAX_ADC_JUMP`) is
+/// treated as a suspected glitch rather than incorporated outright: the
+/// accepted change is clamped to that delta, and the deviation is counted
+/// against `config::voltage_filter::JUMP_CONFIRM_SAMPLES`. Only once that
+/// many consecutive samples keep agreeing on the same large jump - as a
+/// real voltage step (e.g. a charger connecting) would, but a one-off
+/// glitched conversion wouldn't - is it committed outright.
+pub struct VoltageFilter {
+    history: HistoryBuffer<u16, { crate::config::voltage_filter::WINDOW_SAMPLES }>,
+    last_raw: u16,
+    consecutive_rejects: u8,
+    last_sample_rejected: bool,
+}
+
+impl VoltageFilter {
+    /// Create an empty filter; `average()` reads as 0 until the first
+    /// sample is accepted
+    pub const fn new() -> Self {
+        Self {
+            history: HistoryBuffer::new(),
+            last_raw: 0,
+            consecutive_rejects: 0,
+            last_sample_rejected: false,
+        }
+    }
+
+    /// Latest raw ADC sample passed to `update`, regardless of whether it
+    /// was accepted into the moving average
+    pub fn instantaneous(&self) -> u16 {
+        self.last_raw
+    }
+
+    /// Moving average over the accepted samples currently in the ring
+    /// buffer; 0 if none have been accepted yet
+    pub fn average(&self) -> u16 {
+        let count = self.history.len();
+        if count == 0 {
+            return 0;
+        }
+        let sum: u32 = self.history.oldest_ordered().map(|&v| v as u32).sum();
+        (sum / count as u32) as u16
+    }
+
+    /// Whether the most recent `update` call clamped a suspected glitch
+    /// instead of accepting the raw sample outright - a diagnostic for the
+    /// caller to log, not something `VoltageFilter` itself reports anywhere
+    pub fn last_sample_rejected(&self) -> bool {
+        self.last_sample_rejected
+    }
+
+    /// Feed in a new raw ADC sample, returning the updated moving average
+    ///
+    /// A sample deviating from the current average by more than
+    /// `config::voltage_filter::SPIKE_REJECT_DELTA_ADC` is clamped to that
+    /// delta instead of being incorporated directly, unless
+    /// `config::voltage_filter::JUMP_CONFIRM_SAMPLES` consecutive samples
+    /// have now agreed on the same large jump, in which case it's committed
+    /// outright and the window is reset to it - never rejects the very
+    /// first sample, since there's no average yet to compare against.
+    pub fn update(&mut self, raw_adc: u16) -> u16 {
+        self.last_raw = raw_adc;
+
+        if self.history.len() > 0 {
+            let current = self.average();
+            let deviation = raw_adc.abs_diff(current);
+            if deviation > crate::config::voltage_filter::SPIKE_REJECT_DELTA_ADC {
+                self.consecutive_rejects = self.consecutive_rejects.saturating_add(1);
+                self.last_sample_rejected = true;
+
+                if self.consecutive_rejects >= crate::config::voltage_filter::JUMP_CONFIRM_SAMPLES {
+                    // Enough consecutive samples agree this is a real step,
+                    // not a glitch - commit it and start the window over
+                    // from here rather than letting stale pre-jump samples
+                    // drag the average back toward the old value
+                    self.history = HistoryBuffer::new();
+                    self.history.write(raw_adc);
+                    self.consecutive_rejects = 0;
+                    self.last_sample_rejected = false;
+                    return self.average();
+                }
+
+                // Not yet confirmed - clamp the accepted change to the
+                // configured delta rather than following the sample, and
+                // don't feed the clamped value into the average (it isn't a
+                // real measurement)
+                let clamped = if raw_adc > current {
+                    current + crate::config::voltage_filter::SPIKE_REJECT_DELTA_ADC
+                } else {
+                    current - crate::config::voltage_filter::SPIKE_REJECT_DELTA_ADC
+                };
+                return clamped;
+            }
+        }
+
+        self.consecutive_rejects = 0;
+        self.last_sample_rejected = false;
+        self.history.write(raw_adc);
+        self.average()
+    }
+}
+
+impl Default for VoltageFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_sample_is_never_rejected() {
+        let mut filter = VoltageFilter::new();
+        assert_eq!(filter.update(1500), 1500);
+        assert!(!filter.last_sample_rejected());
+    }
+
+    #[test]
+    fn small_deviation_is_accepted_into_the_average() {
+        let mut filter = VoltageFilter::new();
+        filter.update(1500);
+        let result = filter.update(1510);
+        assert!(!filter.last_sample_rejected());
+        assert_eq!(result, 1505);
+    }
+
+    #[test]
+    fn large_one_off_jump_is_clamped_not_followed() {
+        let mut filter = VoltageFilter::new();
+        filter.update(1500);
+        let result = filter.update(3500); // way past SPIKE_REJECT_DELTA_ADC
+        assert!(filter.last_sample_rejected());
+        assert_eq!(result, 1500 + crate::config::voltage_filter::SPIKE_REJECT_DELTA_ADC);
+        // The glitch never entered the average
+        assert_eq!(filter.average(), 1500);
+    }
+
+    #[test]
+    fn a_real_step_is_committed_after_enough_agreeing_samples() {
+        let mut filter = VoltageFilter::new();
+        filter.update(1500);
+
+        // First JUMP_CONFIRM_SAMPLES - 1 repeats are still clamped
+        for _ in 0..crate::config::voltage_filter::JUMP_CONFIRM_SAMPLES - 1 {
+            filter.update(3500);
+            assert!(filter.last_sample_rejected());
+        }
+
+        // The next agreeing sample tips it over the confirmation count
+        let result = filter.update(3500);
+        assert!(!filter.last_sample_rejected());
+        assert_eq!(result, 3500);
+        assert_eq!(filter.average(), 3500);
+    }
+
+    #[test]
+    fn a_rejected_sample_resets_the_consecutive_count_once_it_stops_agreeing() {
+        let mut filter = VoltageFilter::new();
+        filter.update(1500);
+        filter.update(3500); // rejected, consecutive_rejects = 1
+        let result = filter.update(1505); // back in range - accepted normally
+        assert!(!filter.last_sample_rejected());
+        assert_eq!(result, filter.average());
+    }
+}
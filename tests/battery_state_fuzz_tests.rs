@@ -0,0 +1,238 @@
+/// Battery State Machine Fuzzing
+///
+/// Split out of `battery_automation_tests.rs`'s `performance_automation_tests`
+/// module: a seeded-PRNG fuzz test over the battery state machine and charge
+/// controller is its own concern, distinct from build/timing/memory
+/// validation, so it gets its own file.
+
+use std::time::Instant;
+
+use ass_easy_loop::drivers::battery_charge::ChargeController;
+use ass_easy_loop::types::battery::{
+    BatteryHealth, BatteryState, BatteryStateTracker, ChargeState, convert_adc_to_voltage_mv,
+};
+
+/// Automated test execution result
+#[derive(Debug, Clone)]
+pub struct AutomationResult {
+    pub test_name: String,
+    pub success: bool,
+    pub execution_time_ms: u64,
+    pub logs_captured: Vec<String>,
+    pub error_details: Option<String>,
+}
+
+impl AutomationResult {
+    pub fn new(test_name: &str) -> Self {
+        Self {
+            test_name: test_name.to_string(),
+            success: false,
+            execution_time_ms: 0,
+            logs_captured: Vec::new(),
+            error_details: None,
+        }
+    }
+
+    pub fn mark_success(&mut self, duration: std::time::Duration) {
+        self.success = true;
+        self.execution_time_ms = duration.as_millis() as u64;
+    }
+
+    pub fn mark_failure(&mut self, error: String, duration: std::time::Duration) {
+        self.success = false;
+        self.error_details = Some(error);
+        self.execution_time_ms = duration.as_millis() as u64;
+    }
+
+    pub fn add_log(&mut self, log_entry: String) {
+        self.logs_captured.push(log_entry);
+    }
+}
+
+#[cfg(test)]
+mod battery_state_fuzz_tests {
+    use super::*;
+
+    /// Minimal seeded xorshift32 PRNG - deterministic and reproducible from
+    /// a single `u32` seed, which is all the fuzz test needs to log for a
+    /// failure to be replayed exactly.
+    struct XorShift32 {
+        state: u32,
+    }
+
+    impl XorShift32 {
+        fn new(seed: u32) -> Self {
+            // xorshift has no valid all-zero state
+            Self { state: if seed == 0 { 0xdead_beef } else { seed } }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            let mut x = self.state;
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            self.state = x;
+            x
+        }
+
+        /// A reading across the full `u16` ADC range, occasionally drawn
+        /// from the state machine's known threshold/extreme values (0,
+        /// 1425, 1675, 4095, u16::MAX, ...) so edge cases show up often
+        /// instead of only by chance from a uniform draw.
+        fn next_adc_reading(&mut self) -> u16 {
+            const BOUNDARY_POOL: [u16; 12] =
+                [0, 1, 1424, 1425, 1426, 1650, 1651, 1674, 1675, 1676, 4095, u16::MAX];
+            if self.next_u32() % 4 == 0 {
+                BOUNDARY_POOL[(self.next_u32() as usize) % BOUNDARY_POOL.len()]
+            } else {
+                (self.next_u32() % (u16::MAX as u32 + 1)) as u16
+            }
+        }
+    }
+
+    /// Regenerate `count` ADC readings from `seed`. Deterministic, so a
+    /// failing `seed` can be replayed exactly, and a shorter `count`
+    /// reproduces an identical prefix of the same sequence.
+    fn generate_readings(seed: u32, count: usize) -> Vec<u16> {
+        let mut rng = XorShift32::new(seed);
+        (0..count).map(|_| rng.next_adc_reading()).collect()
+    }
+
+    /// A raw temperature-channel ADC reading that decodes (via
+    /// `adc_to_temp_mdeg`) to a room-temperature value safely inside
+    /// `config::battery_temp`'s `[LOW_TEMP_THRESHOLD_MDEG,
+    /// HIGH_TEMP_THRESHOLD_MDEG]` band, so the fuzz loop exercises the
+    /// voltage/state checks in `BatteryHealth::evaluate` without tripping
+    /// its temperature branches (there is no temperature reading in these
+    /// synthetic ADC sequences to fuzz meaningfully).
+    const ROOM_TEMP_ADC: u16 = 1000;
+
+    /// States adjacent to `state` in the Low -> Normal -> Charging -> Full
+    /// order; a single `BatteryStateTracker::update` call committing a
+    /// transition outside this set has skipped over an intermediate
+    /// battery condition. `Fault` is never produced by `update` itself (it
+    /// only falls back to `BatteryState::from_adc_reading`, which never
+    /// returns `Fault`), so it has no adjacency of its own here.
+    fn adjacent_states(state: BatteryState) -> &'static [BatteryState] {
+        match state {
+            BatteryState::Low => &[BatteryState::Normal],
+            BatteryState::Normal => &[BatteryState::Low, BatteryState::Charging],
+            BatteryState::Charging => &[BatteryState::Normal, BatteryState::Full],
+            BatteryState::Full => &[BatteryState::Charging],
+            BatteryState::Fault => &[],
+        }
+    }
+
+    /// Replay `readings` through a fresh `BatteryStateTracker` and
+    /// `ChargeController`, returning the index of the first reading
+    /// that violates a safety invariant and a description of the
+    /// violation, if any.
+    fn first_invariant_violation(readings: &[u16]) -> Option<(usize, String)> {
+        let mut tracker = BatteryStateTracker::new();
+        let mut controller = ChargeController::new(Default::default());
+
+        for (i, &adc) in readings.iter().enumerate() {
+            let previous_state = tracker.state();
+            let new_state = tracker.update(adc);
+
+            if new_state != previous_state && !adjacent_states(previous_state).contains(&new_state) {
+                return Some((i, format!(
+                    "non-adjacent state transition {:?} -> {:?} at index {} (adc={})",
+                    previous_state, new_state, i, adc
+                )));
+            }
+
+            // Charging must never be enabled above the over-voltage cutoff:
+            // drive the charge controller off the same reading (charger
+            // always present, a fixed moderate charge current) and confirm
+            // BatteryHealth::OverVoltage always suspends charging.
+            let voltage_mv = convert_adc_to_voltage_mv(adc);
+            let health = BatteryHealth::evaluate(adc, ROOM_TEMP_ADC, new_state);
+            controller.update(voltage_mv as u32, 200, true, i as u32 * 1000);
+            let charging = controller.state() != ChargeState::NotCharging;
+            if health == BatteryHealth::OverVoltage && charging {
+                return Some((i, format!(
+                    "charging enabled ({:?}) above the over-voltage cutoff at index {} (adc={}, {}mV)",
+                    controller.state(), i, adc, voltage_mv
+                )));
+            }
+        }
+
+        None
+    }
+
+    /// Regenerate `readings` from `seed` and locate the first violating
+    /// index. `readings[0..=index]` is, by construction, already the
+    /// minimal failing sequence: every reading before the violation is
+    /// part of the state machine's history and can't be dropped without
+    /// changing the replay, and nothing after the violation was examined,
+    /// so truncating there reproduces the identical failure with nothing
+    /// extraneous.
+    fn shrink_to_minimal_failure(seed: u32, max_draws: usize) -> Option<(Vec<u16>, String)> {
+        let readings = generate_readings(seed, max_draws);
+        first_invariant_violation(&readings).map(|(index, message)| (readings[..=index].to_vec(), message))
+    }
+
+    /// AUTOMATED TEST: Battery state machine fuzzing
+    ///
+    /// Drives `BatteryStateTracker` and `ChargeController` with
+    /// thousands of pseudo-random ADC readings (seeded xorshift32, biased
+    /// toward threshold/boundary values) and asserts hard safety
+    /// invariants: charging is never enabled above the over-voltage
+    /// cutoff, the state machine never commits a transition between
+    /// non-adjacent states, and no reading panics. On a violation the seed
+    /// is logged so the run is reproducible, and the failing sequence is
+    /// shrunk to its minimal reproducing prefix for `error_details`.
+    #[test]
+    fn test_battery_state_machine_fuzz() {
+        const READINGS_PER_SEED: usize = 4096;
+        const SEEDS: [u32; 5] = [0x1234_5678, 0xC0FF_EE42, 0x0BAD_F00D, 0x5EED_5EED, 0xFACE_FEED];
+
+        let mut result = AutomationResult::new("Battery State Machine Fuzz");
+        let start = Instant::now();
+
+        let mut failing_seed = None;
+        for &seed in &SEEDS {
+            result.add_log(format!("Fuzzing seed=0x{:08x} ({} readings)", seed, READINGS_PER_SEED));
+
+            let outcome = std::panic::catch_unwind(|| {
+                let readings = generate_readings(seed, READINGS_PER_SEED);
+                first_invariant_violation(&readings)
+            });
+
+            match outcome {
+                Ok(None) => {}
+                Ok(Some(_)) => {
+                    failing_seed = Some(seed);
+                    break;
+                }
+                Err(_) => {
+                    result.add_log(format!("Reading sequence panicked for seed=0x{:08x}", seed));
+                    failing_seed = Some(seed);
+                    break;
+                }
+            }
+        }
+
+        if let Some(seed) = failing_seed {
+            let shrunk = std::panic::catch_unwind(|| shrink_to_minimal_failure(seed, READINGS_PER_SEED))
+                .ok()
+                .flatten();
+            let details = match shrunk {
+                Some((readings, message)) => format!(
+                    "seed=0x{:08x} minimal failing sequence ({} readings): {:?} - {}",
+                    seed, readings.len(), readings, message
+                ),
+                None => format!(
+                    "seed=0x{:08x} reproduced a panic that could not be shrunk deterministically",
+                    seed
+                ),
+            };
+            result.mark_failure(details, start.elapsed());
+        } else {
+            result.mark_success(start.elapsed());
+        }
+
+        assert!(result.success, "Battery state machine fuzz failed: {:?}", result.error_details);
+    }
+}
@@ -8,53 +8,76 @@ use ass_easy_loop::types::{
 mod usb_command_parsing_tests {
     use super::*;
 
+    // Report layout: [0] opcode, [1] sequence id, [2..] payload
+
     #[test]
     fn test_enter_bootloader_command_parsing() {
         let mut report = [0u8; 64];
         report[0] = 0x03; // EnterBootloader command
-        
-        let command = parse_hid_report(&report);
-        assert_eq!(command, Some(UsbCommand::EnterBootloader));
+        report[1] = 7; // sequence id
+
+        let request = parse_hid_report(&report);
+        assert_eq!(request.opcode, 0x03);
+        assert_eq!(request.seq, 7);
+        assert_eq!(request.command, Some(UsbCommand::EnterBootloader));
     }
 
     #[test]
     fn test_set_frequency_command_parsing() {
         let mut report = [0u8; 64];
         report[0] = 0x01; // SetFrequency command
-        report[1] = 0x40; // 1000 Hz = 0x03E8
-        report[2] = 0x42;
-        report[3] = 0x0F;
-        report[4] = 0x00;
-        
-        let command = parse_hid_report(&report);
-        assert_eq!(command, Some(UsbCommand::SetFrequency(1000000)));
+        report[1] = 1; // sequence id
+        report[2] = 0x40; // 1000000 = 0x000F4240
+        report[3] = 0x42;
+        report[4] = 0x0F;
+        report[5] = 0x00;
+
+        let request = parse_hid_report(&report);
+        assert_eq!(request.seq, 1);
+        assert_eq!(request.command, Some(UsbCommand::SetFrequency(1000000)));
     }
 
     #[test]
     fn test_set_duty_cycle_command_parsing() {
         let mut report = [0u8; 64];
         report[0] = 0x02; // SetDutyCycle command
-        report[1] = 50;   // 50% duty cycle
-        
-        let command = parse_hid_report(&report);
-        assert_eq!(command, Some(UsbCommand::SetDutyCycle(50)));
+        report[1] = 2; // sequence id
+        report[2] = 50; // 50% duty cycle
+
+        let request = parse_hid_report(&report);
+        assert_eq!(request.seq, 2);
+        assert_eq!(request.command, Some(UsbCommand::SetDutyCycle(50)));
+    }
+
+    #[test]
+    fn test_get_firmware_version_command_parsing() {
+        let mut report = [0u8; 64];
+        report[0] = 0x08; // GetFirmwareVersion command
+        report[1] = 9; // sequence id
+
+        let request = parse_hid_report(&report);
+        assert_eq!(request.seq, 9);
+        assert_eq!(request.command, Some(UsbCommand::GetFirmwareVersion));
     }
 
     #[test]
     fn test_invalid_command_parsing() {
         let mut report = [0u8; 64];
         report[0] = 0xFF; // Invalid command
-        
-        let command = parse_hid_report(&report);
-        assert_eq!(command, None);
+        report[1] = 3; // sequence id is still parsed even for an unknown opcode
+
+        let request = parse_hid_report(&report);
+        assert_eq!(request.opcode, 0xFF);
+        assert_eq!(request.seq, 3);
+        assert_eq!(request.command, None);
     }
 
     #[test]
     fn test_zero_command_parsing() {
         let report = [0u8; 64]; // All zeros
-        
-        let command = parse_hid_report(&report);
-        assert_eq!(command, None);
+
+        let request = parse_hid_report(&report);
+        assert_eq!(request.command, None);
     }
 }
 
@@ -115,12 +138,12 @@ mod integration_tests {
         // Test the complete flow from USB command to bootloader config
         let mut report = [0u8; 64];
         report[0] = 0x03; // EnterBootloader command
-        
-        let command = parse_hid_report(&report);
-        assert_eq!(command, Some(UsbCommand::EnterBootloader));
-        
+
+        let request = parse_hid_report(&report);
+        assert_eq!(request.command, Some(UsbCommand::EnterBootloader));
+
         // Simulate creating config when bootloader command is received
-        if let Some(UsbCommand::EnterBootloader) = command {
+        if let Some(UsbCommand::EnterBootloader) = request.command {
             let config = BootloaderConfig::default();
             assert_eq!(config.activity_pin_mask, 0);
             assert_eq!(config.disable_interface_mask, 0);
@@ -0,0 +1,211 @@
+/// Feature Powerset Build Matrix
+///
+/// Split out of `battery_automation_tests.rs`: this is a general build-matrix
+/// check over `Cargo.toml`'s `[features]` table, not a battery-specific
+/// validation, so it gets its own file.
+
+use std::process::Command;
+use std::time::Instant;
+
+/// Automated test execution result
+#[derive(Debug, Clone)]
+pub struct AutomationResult {
+    pub test_name: String,
+    pub success: bool,
+    pub execution_time_ms: u64,
+    pub logs_captured: Vec<String>,
+    pub error_details: Option<String>,
+}
+
+impl AutomationResult {
+    pub fn new(test_name: &str) -> Self {
+        Self {
+            test_name: test_name.to_string(),
+            success: false,
+            execution_time_ms: 0,
+            logs_captured: Vec::new(),
+            error_details: None,
+        }
+    }
+
+    pub fn mark_success(&mut self, duration: std::time::Duration) {
+        self.success = true;
+        self.execution_time_ms = duration.as_millis() as u64;
+    }
+
+    pub fn mark_failure(&mut self, error: String, duration: std::time::Duration) {
+        self.success = false;
+        self.error_details = Some(error);
+        self.execution_time_ms = duration.as_millis() as u64;
+    }
+
+    pub fn add_log(&mut self, log_entry: String) {
+        self.logs_captured.push(log_entry);
+    }
+}
+
+/// Aggregates `AutomationResult`s collected while sweeping the feature
+/// powerset, so a single assertion can report every failing combination
+/// instead of bailing out of the loop at the first one.
+pub struct TestReporter {
+    results: Vec<AutomationResult>,
+}
+
+impl TestReporter {
+    pub fn new() -> Self {
+        Self { results: Vec::new() }
+    }
+
+    pub fn record(&mut self, result: AutomationResult) {
+        self.results.push(result);
+    }
+
+    pub fn failure_count(&self) -> usize {
+        self.results.iter().filter(|r| !r.success).count()
+    }
+}
+
+/// Execute cargo command with timeout and result capture
+fn execute_cargo_command(args: &[&str], timeout_secs: u64) -> Result<String, String> {
+    let mut cmd = Command::new("cargo");
+    cmd.args(args);
+
+    let start = Instant::now();
+
+    match cmd.output() {
+        Ok(output) => {
+            let duration = start.elapsed();
+            if duration.as_secs() > timeout_secs {
+                return Err(format!("Command timed out after {} seconds", timeout_secs));
+            }
+
+            if output.status.success() {
+                Ok(String::from_utf8_lossy(&output.stdout).to_string())
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                Err(format!("Command failed: {}", stderr))
+            }
+        }
+        Err(e) => Err(format!("Failed to execute command: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod build_matrix_tests {
+    use super::*;
+
+    /// Parse the `[features]` table of `Cargo.toml` into feature names,
+    /// skipping `default` since it's an activation list rather than a gate
+    /// worth check-building in isolation.
+    fn parse_cargo_toml_features(manifest_path: &str) -> Result<Vec<String>, String> {
+        let contents = std::fs::read_to_string(manifest_path)
+            .map_err(|e| format!("Failed to read {}: {}", manifest_path, e))?;
+
+        let mut features = Vec::new();
+        let mut in_features_section = false;
+        for line in contents.lines() {
+            let trimmed = line.trim();
+            if trimmed.starts_with('[') {
+                in_features_section = trimmed == "[features]";
+                continue;
+            }
+            if !in_features_section || trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if let Some((name, _)) = trimmed.split_once('=') {
+                let name = name.trim();
+                if name != "default" {
+                    features.push(name.to_string());
+                }
+            }
+        }
+        Ok(features)
+    }
+
+    /// Mutually-exclusive feature pairs to skip when generating
+    /// combinations (e.g. two logging backends that can't both be active).
+    /// Empty unless the crate grows a real conflict worth encoding here.
+    const MUTUALLY_EXCLUSIVE: &[(&str, &str)] = &[];
+
+    fn is_excluded(combo: &[String]) -> bool {
+        MUTUALLY_EXCLUSIVE
+            .iter()
+            .any(|(a, b)| combo.iter().any(|f| f == a) && combo.iter().any(|f| f == b))
+    }
+
+    /// Build the set of feature combinations to exercise: each feature
+    /// alone, every pairwise combination, and the full feature set - minus
+    /// anything `MUTUALLY_EXCLUSIVE` rules out.
+    fn feature_combinations(features: &[String]) -> Vec<Vec<String>> {
+        let mut combos: Vec<Vec<String>> = Vec::new();
+
+        for f in features {
+            combos.push(vec![f.clone()]);
+        }
+
+        for i in 0..features.len() {
+            for j in (i + 1)..features.len() {
+                combos.push(vec![features[i].clone(), features[j].clone()]);
+            }
+        }
+
+        if features.len() > 2 {
+            combos.push(features.to_vec());
+        }
+
+        combos.retain(|combo| !is_excluded(combo));
+        combos
+    }
+
+    /// AUTOMATED TEST: Feature powerset build matrix
+    ///
+    /// Parses `[features]` from `Cargo.toml` and `check`s
+    /// `thumbv6m-none-eabi` against every individual feature, pairwise
+    /// combination, and the full set (minus `MUTUALLY_EXCLUSIVE` pairs), so
+    /// a `#[cfg(feature = ...)]` that only compiles under the one
+    /// hardcoded combination the other tests build doesn't silently rot.
+    #[test]
+    fn test_feature_powerset_build_matrix() {
+        let features = match parse_cargo_toml_features("Cargo.toml") {
+            Ok(features) => features,
+            Err(e) => {
+                // No manifest to read features from - nothing to validate,
+                // so don't fail the suite over a missing checkout file.
+                println!("Skipping feature powerset matrix: {}", e);
+                return;
+            }
+        };
+
+        let mut reporter = TestReporter::new();
+        for combo in feature_combinations(&features) {
+            let label = combo.join(",");
+            let mut result = AutomationResult::new(&format!("Feature combination: {}", label));
+            let start = Instant::now();
+
+            let check_args = [
+                "check",
+                "--target", "thumbv6m-none-eabi",
+                "--features", label.as_str(),
+                "--all-targets",
+            ];
+
+            match execute_cargo_command(&check_args, 45) {
+                Ok(output) => {
+                    result.add_log(output);
+                    result.mark_success(start.elapsed());
+                }
+                Err(e) => {
+                    result.mark_failure(e, start.elapsed());
+                }
+            }
+
+            reporter.record(result);
+        }
+
+        assert_eq!(
+            reporter.failure_count(),
+            0,
+            "One or more feature combinations failed to build in isolation"
+        );
+    }
+}
@@ -235,6 +235,160 @@ pub enum MockAdcError {
     HardwareError,
 }
 
+/// Smart Battery System (SBS, SMBus) standard command codes
+/// Only the subset this mock models - see the Smart Battery Data
+/// Specification for the full register set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SbsCommand {
+    ManufacturerAccess = 0x00,
+    BatteryMode = 0x03,
+    Temperature = 0x08,
+    Voltage = 0x09,
+    Current = 0x0A,
+    RelativeStateOfCharge = 0x0D,
+    RemainingCapacity = 0x0F,
+    FullChargeCapacity = 0x10,
+    DesignCapacity = 0x18,
+}
+
+/// Error returned by `SmartBatteryMock::write_word` for commands the real
+/// SBS device only exposes as read-only (derived) registers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SbsMockError {
+    ReadOnly(SbsCommand),
+}
+
+/// A preloaded set of SBS register values, for setting up an integration
+/// test scenario in one call instead of a `write_word` per register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmartBatteryScenario {
+    pub voltage_mv: u16,
+    pub current_ma: i16,
+    pub remaining_capacity_mah: u16,
+    pub full_charge_capacity_mah: u16,
+    pub design_capacity_mah: u16,
+    pub temperature_decikelvin: u16,
+}
+
+impl Default for SmartBatteryScenario {
+    /// A healthy pack at rest: ~3.7V, no current flow, 75% state of charge
+    fn default() -> Self {
+        Self {
+            voltage_mv: 3700,
+            current_ma: 0,
+            remaining_capacity_mah: 1500,
+            full_charge_capacity_mah: 2000,
+            design_capacity_mah: 2000,
+            temperature_decikelvin: 2981, // 298.1K = 25.0C
+        }
+    }
+}
+
+/// Mock Smart Battery System (SBS) emulator
+///
+/// Models the standard SBS register/command set as readable/writable
+/// words, so integration tests can exercise `FuelGauge`, `ChargeController`,
+/// and the battery health classification against a realistic battery model
+/// instead of `MockBatteryMonitor`'s lone voltage scalar.
+/// `RelativeStateOfCharge` is always derived from the current
+/// `RemainingCapacity`/`FullChargeCapacity` registers rather than stored,
+/// matching the real SBS device's behavior.
+#[derive(Debug, Clone)]
+pub struct SmartBatteryMock {
+    voltage_mv: Arc<Mutex<u16>>,
+    current_ma: Arc<Mutex<i16>>,
+    remaining_capacity_mah: Arc<Mutex<u16>>,
+    full_charge_capacity_mah: Arc<Mutex<u16>>,
+    design_capacity_mah: Arc<Mutex<u16>>,
+    battery_mode: Arc<Mutex<u16>>,
+    temperature_decikelvin: Arc<Mutex<u16>>,
+}
+
+impl SmartBatteryMock {
+    /// Create a new mock loaded with `SmartBatteryScenario::default()`
+    pub fn new() -> Self {
+        let mock = Self {
+            voltage_mv: Arc::new(Mutex::new(0)),
+            current_ma: Arc::new(Mutex::new(0)),
+            remaining_capacity_mah: Arc::new(Mutex::new(0)),
+            full_charge_capacity_mah: Arc::new(Mutex::new(0)),
+            design_capacity_mah: Arc::new(Mutex::new(0)),
+            battery_mode: Arc::new(Mutex::new(0)),
+            temperature_decikelvin: Arc::new(Mutex::new(0)),
+        };
+        mock.load_scenario(SmartBatteryScenario::default());
+        mock
+    }
+
+    /// Overwrite every register at once from a `SmartBatteryScenario`
+    pub fn load_scenario(&self, scenario: SmartBatteryScenario) {
+        *self.voltage_mv.lock().unwrap() = scenario.voltage_mv;
+        *self.current_ma.lock().unwrap() = scenario.current_ma;
+        *self.remaining_capacity_mah.lock().unwrap() = scenario.remaining_capacity_mah;
+        *self.full_charge_capacity_mah.lock().unwrap() = scenario.full_charge_capacity_mah;
+        *self.design_capacity_mah.lock().unwrap() = scenario.design_capacity_mah;
+        *self.temperature_decikelvin.lock().unwrap() = scenario.temperature_decikelvin;
+    }
+
+    /// Read a register by SBS command code, computing derived values (e.g.
+    /// `RelativeStateOfCharge`) from the underlying registers on every call
+    pub fn read_word(&self, cmd: SbsCommand) -> u16 {
+        match cmd {
+            SbsCommand::ManufacturerAccess => 0,
+            SbsCommand::BatteryMode => *self.battery_mode.lock().unwrap(),
+            SbsCommand::Temperature => *self.temperature_decikelvin.lock().unwrap(),
+            SbsCommand::Voltage => *self.voltage_mv.lock().unwrap(),
+            SbsCommand::Current => *self.current_ma.lock().unwrap() as u16,
+            SbsCommand::RemainingCapacity => *self.remaining_capacity_mah.lock().unwrap(),
+            SbsCommand::FullChargeCapacity => *self.full_charge_capacity_mah.lock().unwrap(),
+            SbsCommand::DesignCapacity => *self.design_capacity_mah.lock().unwrap(),
+            SbsCommand::RelativeStateOfCharge => {
+                let remaining = *self.remaining_capacity_mah.lock().unwrap() as u32;
+                let full = *self.full_charge_capacity_mah.lock().unwrap() as u32;
+                if full == 0 {
+                    0
+                } else {
+                    ((100 * remaining) / full).min(100) as u16
+                }
+            }
+        }
+    }
+
+    /// Write a register by SBS command code
+    /// Returns `Err(SbsMockError::ReadOnly)` for derived registers the real
+    /// device doesn't accept writes to (`RelativeStateOfCharge`,
+    /// `ManufacturerAccess`)
+    pub fn write_word(&self, cmd: SbsCommand, value: u16) -> Result<(), SbsMockError> {
+        match cmd {
+            SbsCommand::BatteryMode => *self.battery_mode.lock().unwrap() = value,
+            SbsCommand::Temperature => *self.temperature_decikelvin.lock().unwrap() = value,
+            SbsCommand::Voltage => *self.voltage_mv.lock().unwrap() = value,
+            SbsCommand::Current => *self.current_ma.lock().unwrap() = value as i16,
+            SbsCommand::RemainingCapacity => *self.remaining_capacity_mah.lock().unwrap() = value,
+            SbsCommand::FullChargeCapacity => {
+                *self.full_charge_capacity_mah.lock().unwrap() = value
+            }
+            SbsCommand::DesignCapacity => *self.design_capacity_mah.lock().unwrap() = value,
+            SbsCommand::RelativeStateOfCharge | SbsCommand::ManufacturerAccess => {
+                return Err(SbsMockError::ReadOnly(cmd));
+            }
+        }
+        Ok(())
+    }
+
+    /// Reset every register to `SmartBatteryScenario::default()`
+    pub fn reset_state(&self) {
+        self.load_scenario(SmartBatteryScenario::default());
+        *self.battery_mode.lock().unwrap() = 0;
+    }
+}
+
+impl Default for SmartBatteryMock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Mock USB HID device for testing USB communication
 /// Simulates the behavior of the actual USB HID interface
 #[derive(Debug, Clone)]
@@ -1483,6 +1637,7 @@ pub enum MockBootloaderError {
 #[derive(Debug, Clone)]
 pub struct MockTestEnvironment {
     pub battery: MockBatteryMonitor,
+    pub smart_battery: SmartBatteryMock,
     pub usb_hid: MockUsbHidDevice,
     pub system_state: MockSystemState,
     pub bootloader: MockBootloaderHardware,
@@ -1554,6 +1709,7 @@ impl MockTestEnvironment {
     pub fn new() -> Self {
         Self {
             battery: MockBatteryMonitor::new(),
+            smart_battery: SmartBatteryMock::new(),
             usb_hid: MockUsbHidDevice::new(),
             system_state: MockSystemState::new(),
             bootloader: MockBootloaderHardware::new(),
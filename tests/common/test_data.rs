@@ -865,6 +865,7 @@ pub mod performance {
                 peak_usb_cpu_percent: 35,
                 measurement_count: 1000,
                 average_cpu_percent: 20,
+                ..Default::default()
             },
             memory_usage: MemoryUsageStats {
                 queue_memory_bytes: 2048,
@@ -873,6 +874,7 @@ pub mod performance {
                 total_memory_bytes: 2560,
                 memory_utilization_percent: 1, // ~1% of 264KB
                 allocation_count: 0,
+                ..Default::default()
             },
             message_performance: MessagePerformanceStats {
                 avg_format_time_us: 25,
@@ -881,6 +883,7 @@ pub mod performance {
                 peak_processing_time_us: 350,
                 messages_processed: 5000,
                 transmission_failures: 5,
+                ..Default::default()
             },
             timing_impact: TimingImpactStats {
                 pemf_timing_deviation_us: 500,
@@ -915,6 +918,7 @@ pub mod performance {
                         peak_usb_cpu_percent: 35 + cpu_load_increase,
                         measurement_count: (i + 1) * 10,
                         average_cpu_percent: 20 + (cpu_load_increase / 2),
+                        ..Default::default()
                     },
                     memory_usage: MemoryUsageStats {
                         queue_memory_bytes: 2048 + memory_growth,
@@ -924,6 +928,7 @@ pub mod performance {
                         memory_utilization_percent: ((2560 + memory_growth) * 100 / (264 * 1024))
                             as u8,
                         allocation_count: 0,
+                        ..Default::default()
                     },
                     message_performance: MessagePerformanceStats {
                         avg_format_time_us: 25 + (time_factor * 5.0) as u32,
@@ -932,6 +937,7 @@ pub mod performance {
                         peak_processing_time_us: 350 + (time_factor * 100.0) as u32,
                         messages_processed: (i + 1) * 50,
                         transmission_failures: (time_factor * 10.0) as u32,
+                        ..Default::default()
                     },
                     timing_impact: TimingImpactStats {
                         pemf_timing_deviation_us: 500 + (time_factor * 200.0) as u32,
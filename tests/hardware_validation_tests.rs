@@ -411,8 +411,8 @@ def parse_log_message(data):
     
     level = data[0]
     module = data[1:9].rstrip(b'\x00').decode('utf-8', errors='ignore')
-    message = data[9:57].rstrip(b'\x00').decode('utf-8', errors='ignore')
-    timestamp = struct.unpack('<I', data[57:61])[0]
+    message = data[9:56].rstrip(b'\x00').decode('utf-8', errors='ignore')
+    timestamp = struct.unpack('<I', data[56:60])[0]
     
     return {{
         'timestamp': timestamp,
@@ -551,8 +551,8 @@ def parse_log_message(data):
     
     level = data[0]
     module = data[1:9].rstrip(b'\x00').decode('utf-8', errors='ignore')
-    message = data[9:57].rstrip(b'\x00').decode('utf-8', errors='ignore')
-    timestamp = struct.unpack('<I', data[57:61])[0]
+    message = data[9:56].rstrip(b'\x00').decode('utf-8', errors='ignore')
+    timestamp = struct.unpack('<I', data[56:60])[0]
     
     return {{
         'timestamp': timestamp,
@@ -730,8 +730,8 @@ def parse_log_message(data):
     
     level = data[0]
     module = data[1:9].rstrip(b'\x00').decode('utf-8', errors='ignore')
-    message = data[9:57].rstrip(b'\x00').decode('utf-8', errors='ignore')
-    timestamp = struct.unpack('<I', data[57:61])[0]
+    message = data[9:56].rstrip(b'\x00').decode('utf-8', errors='ignore')
+    timestamp = struct.unpack('<I', data[56:60])[0]
     
     return {{
         'timestamp': timestamp,
@@ -854,7 +854,7 @@ def parse_log_message(data):
     if len(data) < 64:
         return None
     
-    timestamp = struct.unpack('<I', data[57:61])[0]
+    timestamp = struct.unpack('<I', data[56:60])[0]
     return timestamp
 
 try:
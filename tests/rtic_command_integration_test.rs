@@ -1,267 +0,0 @@
-//! Integration tests for RTIC task coordination with command processing
-//! Tests the integration between USB polling task and command handler task
-//! Requirements: 2.2, 8.1, 8.2, 8.3
-
-#![cfg(test)]
-#![no_std]
-#![no_main]
-
-use ass_easy_loop::{
-    command::parsing::{CommandReport, CommandQueue, CommandParser, ResponseQueue},
-    bootloader::BootloaderEntryManager,
-    system_state::SystemStateHandler,
-    test_processor::TestCommandProcessor,
-    test_framework::{TestResult, TestRunner},
-    assert_no_std, assert_eq_no_std,
-};
-use heapless::Vec;
-
-/// Test result structure for tracking integration test outcomes
-#[derive(Clone, Debug, PartialEq)]
-pub struct MockTestResult {
-    pub test_name: &'static str,
-    pub passed: bool,
-    pub details: &'static str,
-    pub timestamp_ms: u32,
-}
-
-/// Mock system for bootloader command integration testing
-struct MockCommandIntegrationSystem {
-    command_queue: CommandQueue<8>,
-    response_queue: ResponseQueue<8>,
-    command_parser: CommandParser,
-    bootloader_manager: BootloaderEntryManager,
-    system_state_handler: SystemStateHandler,
-    test_processor: TestCommandProcessor,
-    test_results: Vec<MockTestResult, 16>,
-}
-
-impl MockCommandIntegrationSystem {
-    fn new() -> Self {
-        Self {
-            command_queue: CommandQueue::new(),
-            response_queue: ResponseQueue::new(),
-            command_parser: CommandParser::new(),
-            bootloader_manager: BootloaderEntryManager::new(),
-            system_state_handler: SystemStateHandler::new(),
-            test_processor: TestCommandProcessor::new(),
-            test_results: Vec::new(),
-        }
-    }
-}
-
-/// Test 1: Command queue integration with bootloader commands
-/// Tests bootloader command processing through the command queue
-fn test_command_queue_bootloader_integration() -> TestResult {
-    let mut system = MockCommandIntegrationSystem::new();
-    let timestamp_ms = 1000;
-    
-    // Create a bootloader entry command
-    let bootloader_command = CommandReport::new(0x80, 1, &[0x00, 0x00, 0x10, 0x00]).unwrap(); // 4096ms timeout
-    let queued = system.command_queue.enqueue(bootloader_command, timestamp_ms, 5000);
-    assert_no_std!(queued, "Failed to queue bootloader command");
-
-    // Verify command was enqueued successfully
-    let queue_length = system.command_queue.len();
-    assert_no_std!(queue_length > 0, "Command queue is empty after enqueue");
-
-    // Test command dequeuing
-    let dequeued_command = system.command_queue.dequeue();
-    assert_no_std!(dequeued_command.is_some(), "Failed to dequeue bootloader command");
-
-    if let Some(cmd) = dequeued_command {
-        assert_eq_no_std!(cmd.command.command_type, 0x80, "Wrong command type dequeued");
-        assert_eq_no_std!(cmd.command.command_id, 1, "Wrong command ID dequeued");
-    }
-
-    TestResult::Pass
-}
-
-/// Test 2: Bootloader state management integration
-/// Tests bootloader entry state management and hardware validation
-fn test_bootloader_state_management_integration() -> TestResult {
-    let mut system = MockCommandIntegrationSystem::new();
-    let timestamp_ms = 1000;
-    
-    // Test initial bootloader state
-    let initial_state = system.bootloader_manager.get_entry_state();
-    assert_eq_no_std!(initial_state, ass_easy_loop::bootloader::BootloaderEntryState::Normal, "Bootloader not in normal state initially");
-
-    // Test bootloader entry request
-    let entry_result = system.bootloader_manager.request_bootloader_entry(2000, timestamp_ms);
-    assert_no_std!(entry_result.is_ok(), "Failed to request bootloader entry");
-
-    // Test hardware state validation
-    let hardware_state = ass_easy_loop::bootloader::HardwareState {
-        mosfet_state: false,
-        led_state: false,
-        adc_active: false,
-        usb_transmitting: false,
-        pemf_pulse_active: false,
-    };
-    
-    let validation_result = system.bootloader_manager.update_entry_progress(&hardware_state, timestamp_ms + 100);
-    assert_no_std!(validation_result.is_ok(), "Hardware state validation failed");
-
-    TestResult::Pass
-}
-
-/// Test 3: Command parsing integration with bootloader commands
-/// Tests parsing of bootloader entry commands from USB HID reports
-fn test_command_parsing_bootloader_integration() -> TestResult {
-    let mut system = MockCommandIntegrationSystem::new();
-    
-    // Create a valid 64-byte USB HID report for bootloader entry
-    let mut report_buffer = [0u8; 64];
-    report_buffer[0] = 0x80; // Bootloader entry command type
-    report_buffer[1] = 1; // Command ID
-    report_buffer[2] = 4; // Payload length
-    report_buffer[4] = 0x00; // Timeout bytes (4096ms = 0x1000)
-    report_buffer[5] = 0x00;
-    report_buffer[6] = 0x10;
-    report_buffer[7] = 0x00;
-    
-    // Calculate and set authentication token (checksum)
-    let mut checksum = report_buffer[0] ^ report_buffer[1] ^ report_buffer[2];
-    for i in 4..8 {
-        checksum ^= report_buffer[i];
-    }
-    report_buffer[3] = checksum;
-
-    // Test command parsing
-    let parse_result = system.command_parser.parse_command(&report_buffer);
-    let parsing_success = matches!(parse_result, ass_easy_loop::command::parsing::ParseResult::Valid(_));
-    
-    assert_no_std!(parsing_success, "Bootloader command parsing failed");
-
-    // Verify parsed command details
-    if let ass_easy_loop::command::parsing::ParseResult::Valid(command) = parse_result {
-        assert_eq_no_std!(command.command_type, 0x80, "Wrong command type parsed");
-        assert_eq_no_std!(command.command_id, 1, "Wrong command ID parsed");
-        assert_eq_no_std!(command.payload_length, 4, "Wrong payload length parsed");
-    }
-
-    TestResult::Pass
-}
-
-/// Test 4: Response queue integration with bootloader responses
-/// Tests response generation and queuing for bootloader commands
-fn test_response_queue_bootloader_integration() -> TestResult {
-    let mut system = MockCommandIntegrationSystem::new();
-    let timestamp_ms = 1000;
-    
-    // Create a bootloader response
-    let bootloader_response = CommandReport::success_response(1, &[0x80, 0x01, 0x02, 0x03]).unwrap();
-
-    // Test response enqueueing
-    let enqueue_success = system.response_queue.enqueue(bootloader_response, 1, timestamp_ms);
-    assert_no_std!(enqueue_success, "Failed to enqueue bootloader response");
-
-    // Test response dequeuing
-    let dequeued_response = system.response_queue.dequeue();
-    assert_no_std!(dequeued_response.is_some(), "Failed to dequeue bootloader response");
-
-    if let Some(response) = dequeued_response {
-        assert_eq_no_std!(response.command.command_id, 1, "Wrong response command ID");
-    }
-
-    TestResult::Pass
-}
-
-/// Test 5: End-to-end bootloader command workflow
-/// Tests complete workflow from command parsing to bootloader state management
-fn test_end_to_end_bootloader_workflow() -> TestResult {
-    let mut system = MockCommandIntegrationSystem::new();
-    let timestamp_ms = 1000;
-    
-    // Step 1: Parse bootloader command from USB HID report
-    let mut report_buffer = [0u8; 64];
-    report_buffer[0] = 0x80; // Bootloader entry command
-    report_buffer[1] = 1; // Command ID
-    report_buffer[2] = 4; // Payload length
-    report_buffer[4] = 0x00; // Timeout (4096ms)
-    report_buffer[5] = 0x00;
-    report_buffer[6] = 0x10;
-    report_buffer[7] = 0x00;
-    
-    // Calculate checksum
-    let mut checksum = report_buffer[0] ^ report_buffer[1] ^ report_buffer[2];
-    for i in 4..8 {
-        checksum ^= report_buffer[i];
-    }
-    report_buffer[3] = checksum;
-
-    let parse_result = system.command_parser.parse_command(&report_buffer);
-    assert_no_std!(matches!(parse_result, ass_easy_loop::command::parsing::ParseResult::Valid(_)), "Command parsing failed");
-
-    // Step 2: Queue the parsed command
-    if let ass_easy_loop::command::parsing::ParseResult::Valid(command) = parse_result {
-        let queued = system.command_queue.enqueue(command, timestamp_ms, 5000);
-        assert_no_std!(queued, "Failed to queue parsed command");
-    }
-
-    // Step 3: Process the command (simulate command handler)
-    let queued_command = system.command_queue.dequeue();
-    assert_no_std!(queued_command.is_some(), "Failed to dequeue command for processing");
-
-    // Step 4: Request bootloader entry
-    let entry_result = system.bootloader_manager.request_bootloader_entry(2000, timestamp_ms);
-    assert_no_std!(entry_result.is_ok(), "Failed to request bootloader entry");
-
-    // Step 5: Generate response
-    let response = CommandReport::success_response(1, &[0x80]).unwrap();
-    let response_queued = system.response_queue.enqueue(response, 1, timestamp_ms);
-    assert_no_std!(response_queued, "Failed to queue response");
-
-    TestResult::Pass
-}
-
-/// Comprehensive bootloader integration test
-fn test_comprehensive_bootloader_integration() -> TestResult {
-    // Run all bootloader integration tests
-    let test_results = [
-        ("Command queue bootloader integration", test_command_queue_bootloader_integration()),
-        ("Bootloader state management integration", test_bootloader_state_management_integration()),
-        ("Command parsing bootloader integration", test_command_parsing_bootloader_integration()),
-        ("Response queue bootloader integration", test_response_queue_bootloader_integration()),
-        ("End-to-end bootloader workflow", test_end_to_end_bootloader_workflow()),
-    ];
-    
-    // Check if all tests passed
-    for (_test_name, result) in &test_results {
-        if *result != TestResult::Pass {
-            return TestResult::fail("One or more bootloader integration tests failed");
-        }
-    }
-    
-    TestResult::Pass
-}
-
-// Test registration for no_std test framework
-const BOOTLOADER_COMMAND_INTEGRATION_TESTS: &[(&str, fn() -> TestResult)] = &[
-    ("test_command_queue_bootloader_integration", test_command_queue_bootloader_integration),
-    ("test_bootloader_state_management_integration", test_bootloader_state_management_integration),
-    ("test_command_parsing_bootloader_integration", test_command_parsing_bootloader_integration),
-    ("test_response_queue_bootloader_integration", test_response_queue_bootloader_integration),
-    ("test_end_to_end_bootloader_workflow", test_end_to_end_bootloader_workflow),
-    ("test_comprehensive_bootloader_integration", test_comprehensive_bootloader_integration),
-];
-
-#[no_mangle]
-pub extern "C" fn run_bootloader_command_integration_tests() -> u32 {
-    let mut test_runner = TestRunner::new("Bootloader Command Integration Tests");
-    
-    for (test_name, test_fn) in BOOTLOADER_COMMAND_INTEGRATION_TESTS {
-        let _ = test_runner.register_test(test_name, *test_fn);
-    }
-    
-    let results = test_runner.run_all();
-    results.failed_count as u32
-}
-
-// Mock panic handler for tests
-#[cfg(test)]
-#[panic_handler]
-fn panic(_info: &core::panic::PanicInfo) -> ! {
-    loop {}
-}
\ No newline at end of file
@@ -22,25 +22,35 @@ fn test_message_serialization_format() {
     buffer[1..8].copy_from_slice(b"BATTERY");
     buffer[8] = 0; // null padding
     
-    // Simulate message "Low voltage" (48 bytes, null-terminated)
+    // Simulate message "Low voltage" (47 bytes, null-terminated)
     buffer[9..20].copy_from_slice(b"Low voltage");
     // Rest is already zero-initialized
-    
+
     // Simulate timestamp 0x12345678 (little-endian)
     let timestamp = 0x12345678u32;
-    buffer[57..61].copy_from_slice(&timestamp.to_le_bytes());
-    
+    buffer[56..60].copy_from_slice(&timestamp.to_le_bytes());
+
+    // Simulate sequence number 0xDEADBEEF (little-endian)
+    let sequence = 0xDEADBEEFu32;
+    buffer[60..64].copy_from_slice(&sequence.to_le_bytes());
+
     // Verify the format
     assert_eq_no_std!(buffer[0], 2); // Log level
     assert_eq_no_std!(&buffer[1..8], b"BATTERY");
     assert_eq_no_std!(buffer[8], 0);
     assert_eq_no_std!(&buffer[9..20], b"Low voltage");
-    
+
     // Verify timestamp deserialization
     let mut timestamp_bytes = [0u8; 4];
-    timestamp_bytes.copy_from_slice(&buffer[57..61]);
+    timestamp_bytes.copy_from_slice(&buffer[56..60]);
     let recovered_timestamp = u32::from_le_bytes(timestamp_bytes);
     assert_eq_no_std!(recovered_timestamp, 0x12345678);
+
+    // Verify sequence number deserialization
+    let mut sequence_bytes = [0u8; 4];
+    sequence_bytes.copy_from_slice(&buffer[60..64]);
+    let recovered_sequence = u32::from_le_bytes(sequence_bytes);
+    assert_eq_no_std!(recovered_sequence, 0xDEADBEEF);
 }
 
 #[test]
@@ -1,215 +0,0 @@
-//! Standalone unit tests for system state query handlers
-//! 
-//! This module tests the system state query functionality in isolation
-//! to verify the implementation works correctly.
-//! 
-//! This test file has been converted to no_std compatibility as part of task 3
-//! from the comprehensive-unit-test-validation spec.
-
-// Test file - uses std for host-side testing
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-// Import only the specific types we need for testing
-use ass_easy_loop::battery::BatteryState;
-use ass_easy_loop::config::LogConfig;
-
-// Test basic enum functionality
-fn test_battery_state_basic() -> TestResult {
-    let state = BatteryState::Normal;
-    assert_eq!(state, BatteryState::Normal);
-    
-    let low_state = BatteryState::Low;
-    assert_eq!(low_state, BatteryState::Low);
-    
-    let charging_state = BatteryState::Charging;
-    assert_eq!(charging_state, BatteryState::Charging);
-    
-    TestResult::pass()
-}
-
-fn test_log_config_basic() -> TestResult {
-    let config = LogConfig::new();
-    // Test that we can create a LogConfig without errors
-    assert_eq!(config.usb_vid, 0x1234); // From config.rs
-    assert_eq!(config.usb_pid, 0x5678); // From config.rs
-    
-    TestResult::pass()
-}
-
-fn test_battery_state_from_adc() -> TestResult {
-    // Test the battery state logic
-    assert_eq!(BatteryState::from_adc_reading(1000), BatteryState::Low);
-    assert_eq!(BatteryState::from_adc_reading(1425), BatteryState::Low);
-    assert_eq!(BatteryState::from_adc_reading(1500), BatteryState::Normal);
-    assert_eq!(BatteryState::from_adc_reading(1674), BatteryState::Normal);
-    assert_eq!(BatteryState::from_adc_reading(1675), BatteryState::Charging);
-    assert_eq!(BatteryState::from_adc_reading(2000), BatteryState::Charging);
-    
-    TestResult::pass()
-}
-
-fn test_battery_state_thresholds() -> TestResult {
-    let low_state = BatteryState::Low;
-    let (min, max) = low_state.get_thresholds();
-    assert_eq!(min, 0);
-    assert_eq!(max, 1425);
-
-    let normal_state = BatteryState::Normal;
-    let (min, max) = normal_state.get_thresholds();
-    assert_eq!(min, 1425);
-    assert_eq!(max, 1675);
-
-    let charging_state = BatteryState::Charging;
-    let (min, max) = charging_state.get_thresholds();
-    assert_eq!(min, 1675);
-    assert_eq!(max, u16::MAX);
-    
-    TestResult::pass()
-}
-
-fn test_battery_state_transitions() -> TestResult {
-    let current_state = BatteryState::Normal;
-    
-    // Test transition to Low
-    let transition = current_state.should_transition_to(1400);
-    assert!(transition.is_some());
-    match transition {
-        Some(state) => {
-            if state != BatteryState::Low {
-                return TestResult::fail("Expected transition to Low");
-            }
-        }
-        None => return TestResult::fail("Expected transition to Low"),
-    }
-    
-    // Test transition to Charging
-    let transition = current_state.should_transition_to(1700);
-    assert!(transition.is_some());
-    match transition {
-        Some(state) => {
-            if state != BatteryState::Charging {
-                return TestResult::fail("Expected transition to Charging");
-            }
-        }
-        None => return TestResult::fail("Expected transition to Charging"),
-    }
-    
-    // Test no transition (staying in same state)
-    let transition = current_state.should_transition_to(1500);
-    assert!(transition.is_none());
-    
-    TestResult::pass()
-}
-
-fn test_log_config_serialization() -> TestResult {
-    let config = LogConfig::new();
-    let serialized = config.serialize();
-    
-    // Test that serialization produces expected length
-    assert_eq!(serialized.len(), 16);
-    
-    // Test that we can deserialize back
-    let deserialized = LogConfig::deserialize(&serialized);
-    assert!(deserialized.is_ok());
-    
-    // Use match instead of unwrap to avoid Debug requirement
-    match deserialized {
-        Ok(deserialized_config) => {
-            assert_eq!(deserialized_config.usb_vid, config.usb_vid);
-            assert_eq!(deserialized_config.usb_pid, config.usb_pid);
-        }
-        Err(_) => {
-            return TestResult::fail("Failed to deserialize config");
-        }
-    }
-    
-    TestResult::pass()
-}
-
-fn test_log_config_validation() -> TestResult {
-    let config = LogConfig::new();
-    let validation_result = config.validate();
-    assert!(validation_result.is_ok());
-    
-    // Test debug config
-    let debug_config = LogConfig::debug_config();
-    let validation_result = debug_config.validate();
-    assert!(validation_result.is_ok());
-    
-    // Test minimal config
-    let minimal_config = LogConfig::minimal_config();
-    let validation_result = minimal_config.validate();
-    assert!(validation_result.is_ok());
-    
-    TestResult::pass()
-}
-
-fn test_data_structure_sizes() -> TestResult {
-    use core::mem::size_of;
-    
-    // Verify that basic data structures are reasonably sized
-    assert!(size_of::<BatteryState>() <= 4);
-    assert!(size_of::<LogConfig>() <= 32);
-    
-    // Test that we can create instances without issues
-    let _battery_state = BatteryState::Normal;
-    let _log_config = LogConfig::new();
-    
-    TestResult::pass()
-}
-
-fn test_basic_functionality() -> TestResult {
-    // This test verifies that the basic functionality we implemented works
-    // without requiring the full system to compile
-    
-    // Test battery state functionality
-    let battery_state = BatteryState::from_adc_reading(1500);
-    assert_eq!(battery_state, BatteryState::Normal);
-    
-    // Test log config functionality
-    let log_config = LogConfig::new();
-    assert!(log_config.validate().is_ok());
-    
-    // Test serialization round-trip
-    let serialized = log_config.serialize();
-    let deserialized = LogConfig::deserialize(&serialized);
-    assert!(deserialized.is_ok());
-    
-    TestResult::pass()
-}
-
-/// Create and run the system state unit test suite
-/// This function creates a test runner and registers all the test functions
-pub fn run_system_state_tests() -> ass_easy_loop::test_framework::TestSuiteResult {
-    let tests = [
-        ("test_battery_state_basic", test_battery_state_basic as fn() -> TestResult),
-        ("test_log_config_basic", test_log_config_basic as fn() -> TestResult),
-        ("test_battery_state_from_adc", test_battery_state_from_adc as fn() -> TestResult),
-        ("test_battery_state_thresholds", test_battery_state_thresholds as fn() -> TestResult),
-        ("test_battery_state_transitions", test_battery_state_transitions as fn() -> TestResult),
-        ("test_log_config_serialization", test_log_config_serialization as fn() -> TestResult),
-        ("test_log_config_validation", test_log_config_validation as fn() -> TestResult),
-        ("test_data_structure_sizes", test_data_structure_sizes as fn() -> TestResult),
-        ("test_basic_functionality", test_basic_functionality as fn() -> TestResult),
-    ];
-    
-    let runner = create_test_suite("System State Unit Tests", &tests);
-    runner.run_all()
-}
-
-/// Entry point for the no_std test binary
-/// This function is called when the test is executed as a standalone binary
-#[no_mangle]
-pub extern "C" fn main() -> ! {
-    // Run the test suite
-    let _results = run_system_state_tests();
-    
-    // In a real embedded environment, we would send results via USB HID
-    // For now, we just loop indefinitely
-    loop {
-        // Test execution complete
-    }
-}
\ No newline at end of file
@@ -33,6 +33,7 @@ fn test_usb_communication_parameters_validation() {
         error_injection_rate_percent: 0,
         bidirectional_test: true,
         concurrent_messages: 2,
+        vary_bytes: 0,
     };
     assert!(valid_params.validate().is_ok());
 
@@ -84,6 +85,7 @@ fn test_usb_communication_parameters_serialization() {
         error_injection_rate_percent: 5,
         bidirectional_test: true,
         concurrent_messages: 3,
+        vary_bytes: 0,
     };
 
     // Serialize parameters
@@ -211,6 +213,7 @@ fn test_usb_communication_test_execution() {
         error_injection_rate_percent: 0,
         bidirectional_test: true,
         concurrent_messages: 2,
+        vary_bytes: 0,
     };
     
     let test_id = 42;
@@ -271,6 +274,7 @@ fn test_usb_communication_test_completion() {
         error_injection_rate_percent: 0,
         bidirectional_test: true,
         concurrent_messages: 1,
+        vary_bytes: 0,
     };
     
     processor.execute_usb_communication_test(99, params, 54321).unwrap();
@@ -311,6 +315,7 @@ fn test_usb_communication_test_with_integrity_checking() {
         error_injection_rate_percent: 0,
         bidirectional_test: true,
         concurrent_messages: 1,
+        vary_bytes: 0,
     };
     
     processor.execute_usb_communication_test(123, params, 10000).unwrap();
@@ -358,6 +363,7 @@ fn test_usb_communication_test_parameter_edge_cases() {
         error_injection_rate_percent: 0,
         bidirectional_test: false,
         concurrent_messages: 1,
+        vary_bytes: 0,
     };
     assert!(min_params.validate().is_ok());
     
@@ -372,6 +378,7 @@ fn test_usb_communication_test_parameter_edge_cases() {
         error_injection_rate_percent: 100,
         bidirectional_test: true,
         concurrent_messages: 8,
+        vary_bytes: 0,
     };
     assert!(max_params.validate().is_ok());
 }
@@ -426,6 +433,7 @@ fn test_multiple_usb_communication_tests() {
         error_injection_rate_percent: 0,
         bidirectional_test: false,
         concurrent_messages: 1,
+        vary_bytes: 0,
     };
     
     processor.execute_usb_communication_test(1, params1, 1000).unwrap();
@@ -450,6 +458,7 @@ fn test_multiple_usb_communication_tests() {
         error_injection_rate_percent: 0,
         bidirectional_test: true,
         concurrent_messages: 2,
+        vary_bytes: 0,
     };
     
     let result = processor.execute_usb_communication_test(2, params2, 3000);
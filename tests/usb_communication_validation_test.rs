@@ -41,6 +41,62 @@ pub struct UsbTestMessage {
 }
 
 impl UsbTestMessage {
+    /// Byte `index` of a deterministic fill pattern: `0` walks `i % 63` so
+    /// corruption anywhere in the buffer lands on a distinct expected value,
+    /// and `1` is a fixed constant useful for stuck-bit detection. Any other
+    /// value falls back to the `0` pattern.
+    fn pattern_byte(index: usize, pattern: u8) -> u8 {
+        match pattern {
+            1 => 0xAA,
+            _ => (index % 63) as u8,
+        }
+    }
+
+    /// Create a message whose payload is a deterministic fill pattern rather
+    /// than caller-supplied bytes, so corruption can be located by position
+    /// instead of only detected by a whole-message checksum. See
+    /// [`UsbTestMessage::verify_pattern`].
+    pub fn new_with_pattern(
+        message_id: u32,
+        timestamp_ms: u32,
+        len: usize,
+        pattern: u8,
+        is_outbound: bool,
+    ) -> Self {
+        let mut message_data = Vec::new();
+        for i in 0..len.min(64) {
+            if message_data.push(Self::pattern_byte(i, pattern)).is_err() {
+                break; // Vector is full
+            }
+        }
+
+        let mut checksum = message_id;
+        for &byte in &message_data {
+            checksum ^= byte as u32;
+        }
+
+        Self {
+            message_id,
+            timestamp_ms,
+            data: message_data,
+            checksum,
+            is_outbound,
+        }
+    }
+
+    /// Walk the payload against the fill pattern used by
+    /// [`UsbTestMessage::new_with_pattern`] and return the index of the
+    /// first mismatching byte, or `None` if the whole payload matches.
+    /// Unlike the XOR checksum, this pinpoints exactly where corruption
+    /// happened instead of just detecting that it did.
+    pub fn verify_pattern(&self, pattern: u8) -> Option<usize> {
+        self.data
+            .iter()
+            .enumerate()
+            .find(|&(i, &byte)| byte != Self::pattern_byte(i, pattern))
+            .map(|(i, _)| i)
+    }
+
     /// Create a new USB test message with integrity checking
     pub fn new(message_id: u32, timestamp_ms: u32, data: &[u8], is_outbound: bool) -> Self {
         let mut message_data = Vec::new();
@@ -181,6 +237,22 @@ pub struct MockUsbHidDevice {
     pub message_id_counter: u32,
     pub error_injection_enabled: bool,
     pub error_injection_rate: u8,
+    /// Index of the first mismatching byte from the most recent
+    /// [`MockUsbHidDevice::receive_message_verified`] call, or `None` if it
+    /// hasn't been called yet or the payload matched its pattern
+    pub last_pattern_mismatch_index: Option<usize>,
+    /// Message IDs sent via [`MockUsbHidDevice::send_batch`] that haven't
+    /// been acknowledged yet, oldest first
+    pub in_flight: Vec<u32, 8>,
+    /// Highest `in_flight.len()` ever reached, i.e. the deepest pipelining
+    /// a scatter-gather test actually achieved
+    pub peak_in_flight_depth: usize,
+    /// Message IDs in the order [`MockUsbHidDevice::acknowledge`] received
+    /// them, which may differ from send order for pipelined transfers
+    pub completion_order: Vec<u32, 32>,
+    /// Count of acknowledgements that didn't complete the oldest still
+    /// in-flight message, i.e. the device replied out of send order
+    pub reordering_events: u32,
 }
 
 impl MockUsbHidDevice {
@@ -196,6 +268,11 @@ impl MockUsbHidDevice {
             message_id_counter: 0,
             error_injection_enabled: false,
             error_injection_rate: 0,
+            last_pattern_mismatch_index: None,
+            in_flight: Vec::new(),
+            peak_in_flight_depth: 0,
+            completion_order: Vec::new(),
+            reordering_events: 0,
         }
     }
 
@@ -280,6 +357,26 @@ impl MockUsbHidDevice {
         }
     }
 
+    /// Receive a pattern-filled message and verify its payload byte-by-byte,
+    /// recording exactly where corruption landed in
+    /// `last_pattern_mismatch_index` instead of only counting a reception
+    /// error the way a checksum-only check would.
+    pub fn receive_message_verified(
+        &mut self,
+        serialized_data: &[u8],
+        pattern: u8,
+    ) -> Result<UsbTestMessage, &'static str> {
+        let message = self.receive_message(serialized_data)?;
+
+        self.last_pattern_mismatch_index = message.verify_pattern(pattern);
+        if self.last_pattern_mismatch_index.is_some() {
+            self.reception_errors += 1;
+            return Err("Pattern verification failed");
+        }
+
+        Ok(message)
+    }
+
     /// Check if error should be injected based on rate
     fn should_inject_error(&self) -> bool {
         if self.error_injection_rate == 0 {
@@ -301,6 +398,59 @@ impl MockUsbHidDevice {
         
         (transmitted_count, received_count, transmission_errors, reception_errors)
     }
+
+    /// Send up to `concurrent_messages` outbound messages without waiting
+    /// for any to be acknowledged first, modeling a scatter-gather / queued
+    /// transfer instead of strict ping-pong traffic. Returns the assigned
+    /// message IDs in send order; stops early if `send_message` fails (e.g.
+    /// the transmitted-messages buffer is full).
+    pub fn send_batch(&mut self, payloads: &[&[u8]], concurrent_messages: u8) -> Vec<u32, 8> {
+        let mut sent = Vec::new();
+
+        for &data in payloads.iter().take(concurrent_messages as usize) {
+            let message_id = match self.send_message(data) {
+                Ok(message_id) => message_id,
+                Err(_) => break,
+            };
+
+            if self.in_flight.push(message_id).is_err() {
+                break; // In-flight tracking is full
+            }
+            if self.in_flight.len() > self.peak_in_flight_depth {
+                self.peak_in_flight_depth = self.in_flight.len();
+            }
+            if sent.push(message_id).is_err() {
+                break;
+            }
+        }
+
+        sent
+    }
+
+    /// Acknowledge one outstanding message by ID. Acknowledgements may
+    /// arrive out of send order for pipelined/overlapping transfers; this
+    /// records a reordering event whenever the acknowledged message isn't
+    /// the oldest one still in flight.
+    pub fn acknowledge(&mut self, message_id: u32) -> Result<(), &'static str> {
+        let position = self
+            .in_flight
+            .iter()
+            .position(|&id| id == message_id)
+            .ok_or("Message not in flight")?;
+
+        if position != 0 {
+            self.reordering_events += 1;
+        }
+        self.in_flight.remove(position);
+        let _ = self.completion_order.push(message_id);
+
+        Ok(())
+    }
+
+    /// Number of outbound messages sent but not yet acknowledged
+    pub fn in_flight_depth(&self) -> usize {
+        self.in_flight.len()
+    }
 }
 
 // ============================================================================
@@ -319,6 +469,7 @@ fn test_usb_communication_parameters_validation() -> TestResult {
         error_injection_rate_percent: 0,
         bidirectional_test: true,
         concurrent_messages: 2,
+        vary_bytes: 0,
     };
     assert_no_std!(valid_params.validate().is_ok());
 
@@ -399,6 +550,57 @@ fn test_bidirectional_communication_success() -> TestResult {
     TestResult::pass()
 }
 
+fn test_pattern_fill_corruption_detection() -> TestResult {
+    let message = UsbTestMessage::new_with_pattern(1, 1000, 40, 0, true);
+    assert_eq_no_std!(message.verify_pattern(0), None);
+
+    let mut device = MockUsbHidDevice::new(0x5678);
+    device.connect();
+
+    let mut corrupted = message.clone();
+    corrupted.data[17] ^= 0xFF;
+    // Re-derive the checksum so the corruption is only visible via the
+    // pattern walk, not the whole-message XOR check
+    let mut checksum = corrupted.message_id;
+    for &byte in &corrupted.data {
+        checksum ^= byte as u32;
+    }
+    corrupted.checksum = checksum;
+
+    let serialized = corrupted.serialize();
+    let result = device.receive_message_verified(&serialized, 0);
+    assert_no_std!(result.is_err());
+    assert_eq_no_std!(device.last_pattern_mismatch_index, Some(17));
+
+    TestResult::pass()
+}
+
+fn test_scatter_gather_queued_transfer() -> TestResult {
+    let mut device = MockUsbHidDevice::new(0x9abc);
+    device.connect();
+
+    let payloads: [&[u8]; 3] = [b"first", b"second", b"third"];
+    let sent_ids = device.send_batch(&payloads, 3);
+    assert_eq_no_std!(sent_ids.len(), 3);
+    assert_eq_no_std!(device.in_flight_depth(), 3);
+    assert_eq_no_std!(device.peak_in_flight_depth, 3);
+
+    // Acknowledge out of send order: second, then first, then third
+    let ack_result = device.acknowledge(sent_ids[1]);
+    assert_no_std!(ack_result.is_ok());
+    let ack_result = device.acknowledge(sent_ids[0]);
+    assert_no_std!(ack_result.is_ok());
+    let ack_result = device.acknowledge(sent_ids[2]);
+    assert_no_std!(ack_result.is_ok());
+
+    assert_eq_no_std!(device.in_flight_depth(), 0);
+    assert_eq_no_std!(device.reordering_events, 1);
+    assert_eq_no_std!(device.completion_order.len(), 3);
+    assert_eq_no_std!(device.completion_order[0], sent_ids[1]);
+
+    TestResult::pass()
+}
+
 fn test_usb_communication_test_integration() -> TestResult {
     let mut processor = TestCommandProcessor::new();
     
@@ -413,6 +615,7 @@ fn test_usb_communication_test_integration() -> TestResult {
         error_injection_rate_percent: 0,
         bidirectional_test: true,
         concurrent_messages: 2,
+        vary_bytes: 0,
     };
     
     let test_id = 42;
@@ -450,6 +653,8 @@ pub extern "C" fn main() -> ! {
         test_message_integrity_validation,
         test_message_integrity_corruption_detection,
         test_bidirectional_communication_success,
+        test_pattern_fill_corruption_detection,
+        test_scatter_gather_queued_transfer,
         test_usb_communication_test_integration
     );
     
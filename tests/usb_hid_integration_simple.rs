@@ -35,9 +35,9 @@ fn test_log_message_to_hid_report_serialization() {
     assert_eq!(&report.data[1..5], b"TEST"); // Module name
     assert_eq!(&report.data[9..21], b"Test message"); // Message content
     
-    // Verify timestamp (little-endian u32 at bytes 57-60)
+    // Verify timestamp (little-endian u32 at bytes 56-59)
     let timestamp_bytes = 12345u32.to_le_bytes();
-    assert_eq!(&report.data[57..61], &timestamp_bytes);
+    assert_eq!(&report.data[56..60], &timestamp_bytes);
 }
 
 #[test]
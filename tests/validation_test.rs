@@ -5,40 +5,48 @@
 fn validate_log_message_serialization() -> bool {
     // Test data
     let timestamp = 0x12345678u32;
+    let sequence = 0x9ABCDEF0u32;
     let level = 2u8; // LogLevel::Warn
     let module = b"BATTERY\0"; // 8 bytes with null terminator
     let message = b"Low voltage\0"; // Message with null terminator
-    
+
     // Create a 64-byte buffer manually (simulating LogMessage::serialize)
     let mut buffer = [0u8; 64];
-    
+
     // Byte 0: Log level
     buffer[0] = level;
-    
+
     // Bytes 1-8: Module name
     buffer[1..9].copy_from_slice(module);
-    
-    // Bytes 9-56: Message content (first 11 bytes + null terminator)
+
+    // Bytes 9-55: Message content (first 11 bytes + null terminator)
     buffer[9..21].copy_from_slice(message);
     // Rest is already zero-initialized
-    
-    // Bytes 57-60: Timestamp (little-endian)
-    buffer[57..61].copy_from_slice(&timestamp.to_le_bytes());
-    
-    // Bytes 61-63: Reserved (already zero)
-    
+
+    // Bytes 56-59: Timestamp (little-endian)
+    buffer[56..60].copy_from_slice(&timestamp.to_le_bytes());
+
+    // Bytes 60-63: Sequence number (little-endian)
+    buffer[60..64].copy_from_slice(&sequence.to_le_bytes());
+
     // Validate the serialization
     if buffer[0] != level { return false; }
     if &buffer[1..8] != b"BATTERY" { return false; }
     if buffer[8] != 0 { return false; }
     if &buffer[9..20] != b"Low voltage" { return false; }
-    
+
     // Validate timestamp deserialization
     let mut timestamp_bytes = [0u8; 4];
-    timestamp_bytes.copy_from_slice(&buffer[57..61]);
+    timestamp_bytes.copy_from_slice(&buffer[56..60]);
     let recovered_timestamp = u32::from_le_bytes(timestamp_bytes);
     if recovered_timestamp != timestamp { return false; }
-    
+
+    // Validate sequence number deserialization
+    let mut sequence_bytes = [0u8; 4];
+    sequence_bytes.copy_from_slice(&buffer[60..64]);
+    let recovered_sequence = u32::from_le_bytes(sequence_bytes);
+    if recovered_sequence != sequence { return false; }
+
     true
 }
 
@@ -46,51 +54,51 @@ fn validate_log_level_values() -> bool {
     // Validate that log levels have expected values
     // LogLevel::Debug = 0, Info = 1, Warn = 2, Error = 3
     let levels = [0u8, 1u8, 2u8, 3u8];
-    
+
     for (i, &expected) in levels.iter().enumerate() {
         if i as u8 != expected { return false; }
     }
-    
+
     true
 }
 
 fn validate_buffer_format() -> bool {
     // Validate the 64-byte buffer format
     let buffer = [0u8; 64];
-    
+
     // Buffer must be exactly 64 bytes
     if buffer.len() != 64 { return false; }
-    
+
     // Validate field positions
     // Level: byte 0
     // Module: bytes 1-8 (8 bytes)
-    // Message: bytes 9-56 (48 bytes)
-    // Timestamp: bytes 57-60 (4 bytes)
-    // Reserved: bytes 61-63 (3 bytes)
-    
+    // Message: bytes 9-55 (47 bytes)
+    // Timestamp: bytes 56-59 (4 bytes)
+    // Sequence: bytes 60-63 (4 bytes)
+
     let level_pos = 0;
     let module_start = 1;
     let module_end = 9;
     let message_start = 9;
-    let message_end = 57;
-    let timestamp_start = 57;
-    let timestamp_end = 61;
-    let reserved_start = 61;
-    let reserved_end = 64;
-    
+    let message_end = 56;
+    let timestamp_start = 56;
+    let timestamp_end = 60;
+    let sequence_start = 60;
+    let sequence_end = 64;
+
     // Validate positions are correct
     if level_pos != 0 { return false; }
     if module_start != 1 || module_end != 9 { return false; }
-    if message_start != 9 || message_end != 57 { return false; }
-    if timestamp_start != 57 || timestamp_end != 61 { return false; }
-    if reserved_start != 61 || reserved_end != 64 { return false; }
-    
+    if message_start != 9 || message_end != 56 { return false; }
+    if timestamp_start != 56 || timestamp_end != 60 { return false; }
+    if sequence_start != 60 || sequence_end != 64 { return false; }
+
     // Validate field sizes
     if (module_end - module_start) != 8 { return false; }
-    if (message_end - message_start) != 48 { return false; }
+    if (message_end - message_start) != 47 { return false; }
     if (timestamp_end - timestamp_start) != 4 { return false; }
-    if (reserved_end - reserved_start) != 3 { return false; }
-    
+    if (sequence_end - sequence_start) != 4 { return false; }
+
     true
 }
 
@@ -104,9 +112,9 @@ pub fn run_validation() -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    
+
     #[test]
     fn test_validation() {
         assert!(run_validation());
     }
-}
\ No newline at end of file
+}